@@ -1,4 +1,7 @@
-use crate::common::*;
+use crate::{
+    common::*,
+    error::{check_len, PermError},
+};
 
 /// The permutation operator on slice-like types.
 pub trait PermApply<T>
@@ -10,6 +13,15 @@ where
     fn apply(&self, input: &mut T) -> Self::Output;
 }
 
+/// The inverse permutation operator on slice-like types.
+pub trait PermApplyInverse<T>: PermApply<T>
+where
+    T: ?Sized,
+{
+    /// Applies the inverse permutation without building it, which undoes [apply](PermApply::apply).
+    fn apply_inverse(&self, input: &mut T) -> Self::Output;
+}
+
 mod without_std {
     use super::*;
     use crate::perm_type::PermS;
@@ -19,107 +31,505 @@ mod without_std {
 
         fn apply(&self, input: &mut [T; SIZE]) -> Self::Output {
             let mut visited = [false; SIZE];
-            apply(&self.indices, &mut visited, input);
+            apply(&self.indices, &mut visited, input, false);
+        }
+    }
+
+    impl<T, const SIZE: usize> PermApplyInverse<[T; SIZE]> for PermS<SIZE> {
+        fn apply_inverse(&self, input: &mut [T; SIZE]) -> Self::Output {
+            let mut visited = [false; SIZE];
+            apply(&self.indices, &mut visited, input, true);
         }
     }
 
     impl<T, const SIZE: usize> PermApply<[T]> for PermS<SIZE> {
-        type Output = Result<(), &'static str>;
+        type Output = Result<(), PermError>;
 
         fn apply(&self, input: &mut [T]) -> Self::Output {
-            if input.len() != SIZE {
-                return Err("input slice length mismatch");
-            }
+            check_len(SIZE, input.len())?;
+            let mut visited = [false; SIZE];
+            apply(&self.indices, &mut visited, input, false);
+            Ok(())
+        }
+    }
+
+    impl<T, const SIZE: usize> PermApplyInverse<[T]> for PermS<SIZE> {
+        fn apply_inverse(&self, input: &mut [T]) -> Self::Output {
+            check_len(SIZE, input.len())?;
             let mut visited = [false; SIZE];
-            apply(&self.indices, &mut visited, input);
+            apply(&self.indices, &mut visited, input, true);
             Ok(())
         }
     }
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Builds a permuted copy of the array, the same as [apply](PermApply::apply) on a clone.
+        pub fn apply_to_array<T>(&self, input: &[T; SIZE]) -> [T; SIZE]
+        where
+            T: Clone,
+        {
+            let mut elems = gather(&self.indices, input);
+            array::from_fn(|_| elems.next().unwrap().clone())
+        }
+
+        /// Writes a permuted copy of input to output.
+        pub fn apply_into<T>(&self, input: &[T], output: &mut [T]) -> Result<(), PermError>
+        where
+            T: Clone,
+        {
+            check_len(SIZE, input.len())?;
+            check_len(SIZE, output.len())?;
+            output
+                .iter_mut()
+                .zip(gather(&self.indices, input))
+                .for_each(|(slot, elem)| *slot = elem.clone());
+            Ok(())
+        }
+
+        /// Applies the permutation to any storage by calling `swap(first, second)` for each swap of positions.
+        ///
+        /// Running the swaps on a slice gives the same result as [apply](PermApply::apply).
+        pub fn apply_with<F>(&self, swap: F)
+        where
+            F: FnMut(usize, usize),
+        {
+            swap_cycles(&self.indices, &mut [false; SIZE], false, swap);
+        }
+
+        /// Applies the inverse permutation to any storage by calling `swap(first, second)` for each swap of positions.
+        pub fn apply_inverse_with<F>(&self, swap: F)
+        where
+            F: FnMut(usize, usize),
+        {
+            swap_cycles(&self.indices, &mut [false; SIZE], true, swap);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{from_indices::PermFromIndices, perm_trait::Permutation};
+
+        #[test]
+        fn static_apply_copy() {
+            let perm = PermS::from_indices([2, 0, 3, 1]).unwrap();
+            let input = ["a", "b", "c", "d"];
+            let output = perm.apply_to_array(&input);
+            assert_eq!(output, ["c", "a", "d", "b"]);
+
+            let mut expect = input;
+            perm.apply(&mut expect);
+            assert_eq!(output, expect);
+
+            let mut buffer = [""; 4];
+            perm.apply_into(&input, &mut buffer).unwrap();
+            assert_eq!(buffer, expect);
+            assert_eq!(
+                perm.apply_into(&input, &mut [""; 3]),
+                Err(PermError::LengthMismatch {
+                    expected: 4,
+                    actual: 3
+                })
+            );
+            assert!(perm.apply_into(&input[1..], &mut buffer).is_err());
+        }
+
+        #[test]
+        fn static_apply_inverse() {
+            let perm = PermS::from_indices([2, 0, 3, 1, 5, 4]).unwrap();
+            let orig = [10, 11, 12, 13, 14, 15];
+
+            let mut data = orig;
+            perm.apply(&mut data);
+            perm.apply_inverse(&mut data);
+            assert_eq!(data, orig);
+
+            let mut expect = orig;
+            perm.inverse().apply(&mut expect);
+            let mut data = orig;
+            perm.apply_inverse(data.as_mut()).unwrap();
+            assert_eq!(data, expect);
+            assert!(perm.apply_inverse(&mut data[1..]).is_err());
+        }
+
+        #[test]
+        fn static_apply_with() {
+            let perm = PermS::from_indices([3, 0, 4, 1, 2]).unwrap();
+            let orig = ['a', 'b', 'c', 'd', 'e'];
+
+            let mut data = orig;
+            perm.apply_with(|first, second| data.swap(first, second));
+            assert_eq!(data, perm.apply_to_array(&orig));
+
+            let mut swaps = 0;
+            perm.apply_inverse_with(|first, second| {
+                data.swap(first, second);
+                swaps += 1;
+            });
+            assert_eq!(data, orig);
+
+            // (0 3 1)(2 4) takes one swap less than each cycle length
+            assert_eq!(swaps, 3);
+        }
+    }
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
     use crate::perm_type::{PermD, PermS};
 
     impl<T, const SIZE: usize> PermApply<[T; SIZE]> for PermD {
-        type Output = Result<(), &'static str>;
+        type Output = Result<(), PermError>;
 
         fn apply(&self, input: &mut [T; SIZE]) -> Self::Output {
             let len = self.indices.len();
-            if len != SIZE {
-                return Err("input slice length mismatch");
-            }
+            check_len(len, SIZE)?;
             let mut visited = vec![false; len];
-            apply(&self.indices, &mut visited, input);
+            apply(&self.indices, &mut visited, input, false);
             Ok(())
         }
     }
 
+    impl<T, const SIZE: usize> PermApplyInverse<[T; SIZE]> for PermD {
+        fn apply_inverse(&self, input: &mut [T; SIZE]) -> Self::Output {
+            self.apply_inverse(input.as_mut_slice())
+        }
+    }
+
     impl<T> PermApply<[T]> for PermD {
-        type Output = Result<(), &'static str>;
+        type Output = Result<(), PermError>;
 
         fn apply(&self, input: &mut [T]) -> Self::Output {
             let len = self.indices.len();
-            if len != input.len() {
-                return Err("input slice length mismatch");
-            }
+            check_len(len, input.len())?;
+            let mut visited = vec![false; len];
+            apply(&self.indices, &mut visited, input, false);
+            Ok(())
+        }
+    }
+
+    impl<T> PermApplyInverse<[T]> for PermD {
+        fn apply_inverse(&self, input: &mut [T]) -> Self::Output {
+            let len = self.indices.len();
+            check_len(len, input.len())?;
             let mut visited = vec![false; len];
-            apply(&self.indices, &mut visited, input);
+            apply(&self.indices, &mut visited, input, true);
             Ok(())
         }
     }
 
     impl<T> PermApply<Vec<T>> for PermD {
-        type Output = Result<(), &'static str>;
+        type Output = Result<(), PermError>;
 
         fn apply(&self, input: &mut Vec<T>) -> Self::Output {
             let len = self.indices.len();
-            if len != input.len() {
-                return Err("input slice length mismatch");
-            }
+            check_len(len, input.len())?;
             let mut visited = vec![false; len];
-            apply(&self.indices, &mut visited, input);
+            apply(&self.indices, &mut visited, input, false);
             Ok(())
         }
     }
 
+    impl<T> PermApplyInverse<Vec<T>> for PermD {
+        fn apply_inverse(&self, input: &mut Vec<T>) -> Self::Output {
+            self.apply_inverse(input.as_mut_slice())
+        }
+    }
+
     impl<T, const SIZE: usize> PermApply<Vec<T>> for PermS<SIZE> {
-        type Output = Result<(), &'static str>;
+        type Output = Result<(), PermError>;
 
         fn apply(&self, input: &mut Vec<T>) -> Self::Output {
             self.apply(input.as_mut_slice())
         }
     }
-}
 
-fn apply<T>(indices: &[usize], visited: &mut [bool], slice: &mut [T]) {
-    unsafe { apply_unsafe(indices, visited, slice.as_mut_ptr()) }
-}
+    impl<T, const SIZE: usize> PermApplyInverse<Vec<T>> for PermS<SIZE> {
+        fn apply_inverse(&self, input: &mut Vec<T>) -> Self::Output {
+            self.apply_inverse(input.as_mut_slice())
+        }
+    }
 
-unsafe fn apply_unsafe<T>(indices: &[usize], visited: &mut [bool], slice: *mut T) {
-    let len = indices.len();
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Builds a permuted copy of the slice.
+        pub fn apply_to_vec<T>(&self, input: &[T]) -> Result<Vec<T>, PermError>
+        where
+            T: Clone,
+        {
+            check_len(SIZE, input.len())?;
+            Ok(gather(&self.indices, input).cloned().collect())
+        }
+    }
 
-    for idx in 0..len {
-        let mut dst = idx;
+    impl PermD {
+        /// Builds a permuted copy of the slice, the same as [apply](PermApply::apply) on a clone.
+        pub fn apply_to_vec<T>(&self, input: &[T]) -> Result<Vec<T>, PermError>
+        where
+            T: Clone,
+        {
+            check_len(self.indices.len(), input.len())?;
+            Ok(gather(&self.indices, input).cloned().collect())
+        }
 
-        if visited[dst] {
-            continue;
+        /// Builds a permuted copy of the array.
+        pub fn apply_to_array<T, const SIZE: usize>(
+            &self,
+            input: &[T; SIZE],
+        ) -> Result<[T; SIZE], PermError>
+        where
+            T: Clone,
+        {
+            check_len(self.indices.len(), SIZE)?;
+            let mut elems = gather(&self.indices, input);
+            Ok(array::from_fn(|_| elems.next().unwrap().clone()))
         }
 
-        loop {
-            visited[dst] = true;
+        /// Writes a permuted copy of input to output.
+        pub fn apply_into<T>(&self, input: &[T], output: &mut [T]) -> Result<(), PermError>
+        where
+            T: Clone,
+        {
+            let len = self.indices.len();
+            check_len(len, input.len())?;
+            check_len(len, output.len())?;
+            output
+                .iter_mut()
+                .zip(gather(&self.indices, input))
+                .for_each(|(slot, elem)| *slot = elem.clone());
+            Ok(())
+        }
 
-            let src = indices[dst];
-            if visited[src] {
-                break;
-            }
+        /// Applies the permutation to any storage by calling `swap(first, second)` for each swap of positions.
+        ///
+        /// Running the swaps on a slice gives the same result as [apply](PermApply::apply).
+        pub fn apply_with<F>(&self, swap: F)
+        where
+            F: FnMut(usize, usize),
+        {
+            let len = self.indices.len();
+            swap_cycles(&self.indices, &mut vec![false; len], false, swap);
+        }
+
+        /// Applies the inverse permutation to any storage by calling `swap(first, second)` for each swap of positions.
+        pub fn apply_inverse_with<F>(&self, swap: F)
+        where
+            F: FnMut(usize, usize),
+        {
+            let len = self.indices.len();
+            swap_cycles(&self.indices, &mut vec![false; len], true, swap);
+        }
+
+        /// Applies the permutation in place without heap allocation.
+        ///
+        /// The visited flags are kept by temporarily complementing the permutation's own indices,
+        /// which are restored before returning.
+        pub fn apply_marking<T>(&mut self, input: &mut [T]) -> Result<(), PermError> {
+            let len = self.indices.len();
+            check_len(len, input.len())?;
+            let indices = self.indices.as_mut_slice();
+
+            // a complemented index is never less than len
+            (0..len).for_each(|start| {
+                if indices[start] >= len {
+                    return;
+                }
 
-            mem::swap(
-                slice.add(src).as_mut().unwrap(),
-                slice.add(dst).as_mut().unwrap(),
+                let mut dst = start;
+                let mut src = indices[start];
+                indices[start] = !src;
+
+                while src != start {
+                    input.swap(src, dst);
+                    let next = indices[src];
+                    indices[src] = !next;
+                    dst = src;
+                    src = next;
+                }
+            });
+
+            indices.iter_mut().for_each(|index| *index = !*index);
+            Ok(())
+        }
+
+        /// Applies the permutation in place with caller-provided visited flags, which
+        /// must have the same length and are cleared before use.
+        pub fn apply_with_visited<T>(
+            &self,
+            input: &mut [T],
+            visited: &mut [bool],
+        ) -> Result<(), PermError> {
+            let len = self.indices.len();
+            check_len(len, input.len())?;
+            check_len(len, visited.len())?;
+            visited.iter_mut().for_each(|flag| *flag = false);
+            apply(&self.indices, visited, input, false);
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::perm_trait::Permutation;
+        use rand::prelude::*;
+        use std::collections::VecDeque;
+
+        #[test]
+        fn dynamic_apply_copy() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let input: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+
+            let mut expect = input.clone();
+            perm.apply(&mut expect).unwrap();
+            assert_eq!(perm.apply_to_vec(&input).unwrap(), expect);
+
+            let mut buffer = vec![0; 1000];
+            perm.apply_into(&input, &mut buffer).unwrap();
+            assert_eq!(buffer, expect);
+            assert!(perm.apply_into(&input, &mut buffer[1..]).is_err());
+            assert!(perm.apply_to_vec(&input[1..]).is_err());
+
+            let perm = PermD::cycle(3);
+            assert_eq!(perm.apply_to_array(&[1, 2, 3]).unwrap(), [3, 1, 2]);
+            assert!(perm.apply_to_array(&[1, 2]).is_err());
+            assert_eq!(
+                PermS::<3>::cycle().apply_to_vec(&[1, 2, 3]).unwrap(),
+                vec![3, 1, 2]
             );
-            dst = src;
+        }
+
+        #[test]
+        fn dynamic_apply_inverse() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let input: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+
+            let mut data = input.clone();
+            perm.apply_inverse(&mut data).unwrap();
+            assert_eq!(data, perm.inverse().apply_to_vec(&input).unwrap());
+            perm.apply(&mut data).unwrap();
+            assert_eq!(data, input);
+            assert!(perm.apply_inverse(&mut data[1..]).is_err());
+        }
+
+        #[test]
+        fn dynamic_apply_marking() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let input: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+            let expect = perm.apply_to_vec(&input).unwrap();
+
+            let mut marked = perm.clone();
+            let mut data = input.clone();
+            marked.apply_marking(&mut data).unwrap();
+            assert_eq!(data, expect);
+            assert_eq!(marked, perm);
+            assert!(marked.apply_marking(&mut data[1..]).is_err());
+            assert_eq!(marked, perm);
+        }
+
+        #[test]
+        fn dynamic_apply_with_visited() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let input: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+            let expect = perm.apply_to_vec(&input).unwrap();
+
+            let mut visited = vec![false; 1000];
+            let mut data = input.clone();
+            perm.apply_with_visited(&mut data, &mut visited).unwrap();
+            assert_eq!(data, expect);
+            assert!(perm
+                .apply_with_visited(&mut data, &mut visited[1..])
+                .is_err());
+        }
+
+        #[test]
+        fn dynamic_apply_with_dirty_visited() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let input: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+
+            // flags left over from another use
+            let mut visited: Vec<bool> = (0..1000).map(|_| rng.gen()).collect();
+            let mut data = input.clone();
+            perm.apply_with_visited(&mut data, &mut visited).unwrap();
+            assert_eq!(data, perm.apply_to_vec(&input).unwrap());
+
+            // flags left over from the previous apply
+            let other = PermD::reverse_cycle(1000);
+            let mut data = input.clone();
+            other.apply_with_visited(&mut data, &mut visited).unwrap();
+            assert_eq!(data, other.apply_to_vec(&input).unwrap());
+        }
+
+        #[test]
+        fn dynamic_apply_with() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let input: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+            let expect = perm.apply_to_vec(&input).unwrap();
+
+            let mut deque: VecDeque<_> = input.iter().copied().collect();
+            perm.apply_with(|first, second| deque.swap(first, second));
+            assert!(deque.iter().eq(expect.iter()));
+            perm.apply_inverse_with(|first, second| deque.swap(first, second));
+            assert!(deque.iter().eq(input.iter()));
         }
     }
 }
+
+/// Iterates over `input[indices[i]]`, the elements of the permuted copy in order.
+fn gather<'a, T>(indices: &'a [usize], input: &'a [T]) -> impl Iterator<Item = &'a T> + 'a {
+    indices.iter().map(move |&index| &input[index])
+}
+
+fn apply<T>(indices: &[usize], visited: &mut [bool], slice: &mut [T], inverse: bool) {
+    unsafe { apply_unsafe(indices, visited, slice.as_mut_ptr(), inverse) }
+}
+
+/// Permutes the slice by swaps along each cycle.
+unsafe fn apply_unsafe<T>(indices: &[usize], visited: &mut [bool], slice: *mut T, inverse: bool) {
+    swap_cycles(indices, visited, inverse, |src, dst| {
+        mem::swap(
+            slice.add(src).as_mut().unwrap(),
+            slice.add(dst).as_mut().unwrap(),
+        );
+    });
+}
+
+/// Calls `swap(src, dst)` for each swap of positions that permutes the cycles.
+///
+/// The forward direction swaps consecutive elements of the cycle, while the inverse direction
+/// swaps each element with the cycle start, which rotates the cycle the other way.
+/// The visited flags must be unset, and each index is read once.
+pub(crate) fn swap_cycles<F>(indices: &[usize], visited: &mut [bool], inverse: bool, mut swap: F)
+where
+    F: FnMut(usize, usize),
+{
+    (0..indices.len()).for_each(|start| {
+        if visited[start] {
+            return;
+        }
+        visited[start] = true;
+
+        let mut dst = start;
+        let mut src = indices[start];
+        while src != start {
+            visited[src] = true;
+            swap(src, dst);
+            if !inverse {
+                dst = src;
+            }
+            src = indices[src];
+        }
+    });
+}
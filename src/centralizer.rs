@@ -0,0 +1,350 @@
+use crate::{
+    common::*,
+    cycles::sorted_cycle_starts,
+    perm_trait::{gcd, Permutation},
+};
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Computes the commutator `self⁻¹ · other⁻¹ · self · other`.
+        pub fn commutator(&self, other: &PermS<SIZE>) -> Self {
+            &(&(&self.inverse() * &other.inverse()) * self) * other
+        }
+
+        /// Finds a permutation `root` with `root.pow(exp)` equal to the permutation.
+        ///
+        /// It returns `None` if no such root exists. Every permutation is its own first root,
+        /// while only the identity has a zeroth root.
+        pub fn root(&self, exp: u32) -> Option<Self> {
+            let mut root = Self::identity();
+            let found = kth_root(
+                &self.indices,
+                exp,
+                &mut root.indices,
+                &mut [0; SIZE],
+                &mut [0; SIZE],
+            );
+            found.then_some(root)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::from_indices::PermFromIndices;
+
+        #[test]
+        fn static_roots() {
+            let lhs = PermS::<3>::swap(0, 1).unwrap();
+            let rhs = PermS::<3>::swap(1, 2).unwrap();
+            let commutator = lhs.commutator(&rhs);
+            assert_eq!(commutator, PermS::from_indices([2, 0, 1]).unwrap());
+            assert_eq!(lhs.commutator(&lhs), PermS::<3>::identity());
+
+            // (0 1)(2 3) is the square of a 4-cycle, while (0 1) has no square root
+            let perm = PermS::from_indices([1, 0, 3, 2]).unwrap();
+            let root = perm.root(2).unwrap();
+            assert_eq!(root.pow(2), perm);
+            assert_eq!(root.cycle_type(), [4, 0, 0, 0]);
+            assert!(PermS::<4>::swap(0, 1).unwrap().root(2).is_none());
+            assert_eq!(
+                PermS::<4>::swap(0, 1).unwrap().root(3).unwrap().pow(3),
+                PermS::<4>::swap(0, 1).unwrap()
+            );
+            assert_eq!(perm.root(1).as_ref(), Some(&perm));
+            assert!(perm.root(0).is_none());
+            assert_eq!(PermS::<4>::identity().root(0), Some(PermS::identity()));
+
+            (0..720).for_each(|rank| {
+                let perm = PermS::<6>::from_rank(rank).unwrap();
+                (1..=6).for_each(|exp| {
+                    if let Some(root) = perm.root(exp) {
+                        assert_eq!(root.pow(exp), perm);
+                    }
+                });
+            });
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{
+        error::PermError,
+        from_indices::PermFromIndices,
+        group::PermGroup,
+        perm_type::{PermD, PermS},
+        product::PermProduct,
+    };
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Gets the generators of the centralizer in the symmetric group, the permutations commuting with this one.
+        pub fn centralizer_generators(&self) -> Vec<Self> {
+            centralizer_generators(&self.indices)
+                .into_iter()
+                .map(|indices| Self::from_indices(indices.as_slice()).unwrap())
+                .collect()
+        }
+
+        /// Enumerates all roots `root` with `root.pow(exp)` equal to the permutation.
+        ///
+        /// The roots are searched within the centralizer, which takes time proportional to its order.
+        pub fn roots(&self, exp: u32) -> Vec<Self> {
+            PermGroup::new_static(&self.centralizer_generators())
+                .elements()
+                .filter(|root| root.pow(exp) == *self)
+                .collect()
+        }
+    }
+
+    impl PermD {
+        /// Computes the commutator `self⁻¹ · other⁻¹ · self · other`.
+        pub fn commutator(&self, other: &PermD) -> Result<Self, PermError> {
+            self.inverse()
+                .perm_product(&other.inverse())?
+                .perm_product(self)?
+                .perm_product(other)
+        }
+
+        /// Finds a permutation `root` with `root.pow(exp)` equal to the permutation.
+        ///
+        /// It returns `None` if no such root exists. Every permutation is its own first root,
+        /// while only the identity has a zeroth root.
+        pub fn root(&self, exp: u32) -> Option<Self> {
+            let len = self.indices.len();
+            let mut root = Self::identity(len);
+            let found = kth_root(
+                &self.indices,
+                exp,
+                &mut root.indices,
+                &mut vec![0; len],
+                &mut vec![0; len],
+            );
+            found.then_some(root)
+        }
+
+        /// Gets the generators of the centralizer in the symmetric group, the permutations commuting with this one.
+        pub fn centralizer_generators(&self) -> Vec<Self> {
+            centralizer_generators(&self.indices)
+                .into_iter()
+                .map(|indices| Self::from_indices(indices).unwrap())
+                .collect()
+        }
+
+        /// Enumerates all roots `root` with `root.pow(exp)` equal to the permutation.
+        ///
+        /// The roots are searched within the centralizer, which takes time proportional to its order.
+        pub fn roots(&self, exp: u32) -> Vec<Self> {
+            PermGroup::new(self.indices.len(), &self.centralizer_generators())
+                .unwrap()
+                .elements()
+                .filter(|root| root.pow(exp) == *self)
+                .collect()
+        }
+    }
+
+    /// Builds the generators rotating a cycle, swapping two cycles and shifting all cycles of each length.
+    fn centralizer_generators(indices: &[usize]) -> Vec<Vec<usize>> {
+        let len = indices.len();
+        let mut lens = vec![0; len];
+        let mut starts = vec![0; len];
+        let num_cycles = sorted_cycle_starts(indices, &mut lens, &mut starts);
+        let identity: Vec<_> = (0..len).collect();
+        let mut generators = vec![];
+
+        length_classes(&starts[..num_cycles], &lens).for_each(|class| {
+            let first = class[0];
+
+            if lens[first] > 1 {
+                let mut rotation = identity.clone();
+                walk_pair(indices, first, first, |point, _| {
+                    rotation[point] = indices[point]
+                });
+                generators.push(rotation);
+            }
+
+            if class.len() > 1 {
+                let mut swap = identity.clone();
+                walk_pair(indices, first, class[1], |lhs, rhs| {
+                    swap[lhs] = rhs;
+                    swap[rhs] = lhs;
+                });
+                generators.push(swap);
+            }
+
+            if class.len() > 2 {
+                let mut shift = identity.clone();
+                class.iter().enumerate().for_each(|(nth, &start)| {
+                    let next = class[(nth + 1) % class.len()];
+                    walk_pair(indices, start, next, |lhs, rhs| shift[lhs] = rhs);
+                });
+                generators.push(shift);
+            }
+        });
+
+        generators
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn centralizers_and_roots() {
+            let lhs = PermD::cycle(4);
+            let rhs = PermD::swap(4, 0, 2).unwrap();
+            let commutator = lhs.commutator(&rhs).unwrap();
+            assert_eq!(
+                commutator,
+                &lhs.inverse() * &lhs.conjugate_with(&rhs).unwrap()
+            );
+            assert!(lhs.commutator(&PermD::cycle(5)).is_err());
+
+            // the centralizer of cycle type (3, 3, 1) has order 3² · 2! · 1
+            let perm = PermD::from_indices(vec![1, 2, 0, 4, 5, 3, 6]).unwrap();
+            let generators = perm.centralizer_generators();
+            assert!(generators
+                .iter()
+                .all(|generator| generator * &perm == &perm * generator));
+            let centralizer = PermGroup::new(7, &generators).unwrap();
+            assert_eq!(centralizer.order(), Some(18));
+            assert_eq!(
+                PermGroup::new(6, &PermD::identity(6).centralizer_generators())
+                    .unwrap()
+                    .order(),
+                Some(720)
+            );
+
+            // the square roots of identity are the involutions
+            assert_eq!(PermD::identity(4).roots(2).len(), 10);
+            let perm = PermD::from_indices(vec![1, 0, 3, 2]).unwrap();
+            let roots = perm.roots(2);
+            assert_eq!(roots.len(), 2);
+            assert!(roots.iter().all(|root| root.cycle_type() == vec![4]));
+
+            // a root is found whenever any root exists
+            (0..120).for_each(|rank| {
+                let perm = PermD::from_rank(5, rank).unwrap();
+                (0..=4).for_each(|exp| {
+                    let roots = perm.roots(exp);
+                    assert_eq!(perm.root(exp).is_some(), !roots.is_empty());
+                    assert!(roots.iter().all(|root| root.pow(exp) == perm));
+                });
+            });
+
+            assert_eq!(PermS::<4>::identity().roots(2).len(), 10);
+            assert_eq!(
+                PermS::<6>::cycle().roots(5),
+                vec![PermS::<6>::reverse_cycle()]
+            );
+        }
+    }
+}
+
+/// Writes a root of indices and returns false if no root exists.
+fn kth_root(
+    indices: &[usize],
+    exp: u32,
+    root: &mut [usize],
+    lens: &mut [usize],
+    starts: &mut [usize],
+) -> bool {
+    if exp == 0 {
+        return indices
+            .iter()
+            .enumerate()
+            .all(|(point, &index)| point == index);
+    }
+
+    let exp = exp as usize;
+    let num_cycles = sorted_cycle_starts(indices, lens, starts);
+
+    let starts = &starts[..num_cycles];
+    length_classes(starts, lens).all(|class| {
+        // a cycle of the root splits into gcd(len, exp) cycles of the power,
+        // so cycles of the same length are merged in groups of the least valid size
+        let len = lens[class[0]];
+        let mut coprime = exp;
+        loop {
+            let common = gcd(coprime as u128, len as u128) as usize;
+            if common == 1 {
+                break;
+            }
+            coprime /= common;
+        }
+        let merged = exp / coprime;
+
+        if class.len() % merged != 0 {
+            return false;
+        }
+
+        let step = mod_inverse(coprime, len);
+        class
+            .chunks(merged)
+            .for_each(|chunk| merge_cycles(indices, chunk, step, root));
+        true
+    })
+}
+
+/// Writes the cycle visiting the cycles in the chunk in turns, whose power of order
+/// `chunk.len() * coprime` is the product of the cycles, where `step` is the inverse of
+/// `coprime` modulo the cycle length.
+fn merge_cycles(indices: &[usize], chunk: &[usize], step: usize, root: &mut [usize]) {
+    chunk.windows(2).for_each(|pair| {
+        walk_pair(indices, pair[0], pair[1], |lhs, rhs| root[lhs] = rhs);
+    });
+
+    let last = chunk[chunk.len() - 1];
+    let target = (0..step).fold(chunk[0], |point, _| indices[point]);
+    walk_pair(indices, last, target, |lhs, rhs| root[lhs] = rhs);
+}
+
+/// Walks two cycles of the same length simultaneously from given points.
+fn walk_pair<F>(indices: &[usize], lhs: usize, rhs: usize, mut f: F)
+where
+    F: FnMut(usize, usize),
+{
+    let mut curr = (lhs, rhs);
+    loop {
+        f(curr.0, curr.1);
+        curr = (indices[curr.0], indices[curr.1]);
+        if curr.0 == lhs {
+            break;
+        }
+    }
+}
+
+/// Splits the cycle starts sorted by lengths into runs of equal lengths.
+fn length_classes<'a>(
+    mut starts: &'a [usize],
+    lens: &'a [usize],
+) -> impl Iterator<Item = &'a [usize]> + 'a {
+    iter::from_fn(move || {
+        let len = lens[*starts.first()?];
+        let end = starts
+            .iter()
+            .take_while(|&&start| lens[start] == len)
+            .count();
+        let (class, rest) = starts.split_at(end);
+        starts = rest;
+        Some(class)
+    })
+}
+
+/// Computes the inverse of a value coprime to the modulus.
+fn mod_inverse(value: usize, modulus: usize) -> usize {
+    let modulus = modulus as i128;
+    let (mut prev_rem, mut rem) = (value as i128 % modulus, modulus);
+    let (mut prev_coef, mut coef) = (1i128, 0i128);
+
+    while rem != 0 {
+        let quotient = prev_rem / rem;
+        (prev_rem, rem) = (rem, prev_rem - quotient * rem);
+        (prev_coef, coef) = (coef, prev_coef - quotient * coef);
+    }
+    prev_coef.rem_euclid(modulus) as usize
+}
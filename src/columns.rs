@@ -0,0 +1,219 @@
+use crate::{
+    apply::swap_cycles,
+    error::{check_len, PermError},
+};
+
+/// Parallel columns of equal length permuted together, such as a tuple or a slice of mutable slices.
+pub trait PermColumns {
+    /// Checks that every column has the given length.
+    fn check_len(&self, len: usize) -> Result<(), PermError>;
+
+    /// Swaps two rows in every column.
+    fn swap_rows(&mut self, first: usize, second: usize);
+}
+
+impl<'a, A, B> PermColumns for (&'a mut [A], &'a mut [B]) {
+    fn check_len(&self, len: usize) -> Result<(), PermError> {
+        check_len(len, self.0.len())?;
+        check_len(len, self.1.len())
+    }
+
+    fn swap_rows(&mut self, first: usize, second: usize) {
+        self.0.swap(first, second);
+        self.1.swap(first, second);
+    }
+}
+
+impl<'a, A, B, C> PermColumns for (&'a mut [A], &'a mut [B], &'a mut [C]) {
+    fn check_len(&self, len: usize) -> Result<(), PermError> {
+        check_len(len, self.0.len())?;
+        check_len(len, self.1.len())?;
+        check_len(len, self.2.len())
+    }
+
+    fn swap_rows(&mut self, first: usize, second: usize) {
+        self.0.swap(first, second);
+        self.1.swap(first, second);
+        self.2.swap(first, second);
+    }
+}
+
+impl<'a, A, B, C, D> PermColumns for (&'a mut [A], &'a mut [B], &'a mut [C], &'a mut [D]) {
+    fn check_len(&self, len: usize) -> Result<(), PermError> {
+        check_len(len, self.0.len())?;
+        check_len(len, self.1.len())?;
+        check_len(len, self.2.len())?;
+        check_len(len, self.3.len())
+    }
+
+    fn swap_rows(&mut self, first: usize, second: usize) {
+        self.0.swap(first, second);
+        self.1.swap(first, second);
+        self.2.swap(first, second);
+        self.3.swap(first, second);
+    }
+}
+
+impl<T> PermColumns for &mut [&mut [T]] {
+    fn check_len(&self, len: usize) -> Result<(), PermError> {
+        self.iter()
+            .try_for_each(|column| check_len(len, column.len()))
+    }
+
+    fn swap_rows(&mut self, first: usize, second: usize) {
+        self.iter_mut()
+            .for_each(|column| column.swap(first, second));
+    }
+}
+
+impl<T, const COLUMNS: usize> PermColumns for [&mut [T]; COLUMNS] {
+    fn check_len(&self, len: usize) -> Result<(), PermError> {
+        self.iter()
+            .try_for_each(|column| check_len(len, column.len()))
+    }
+
+    fn swap_rows(&mut self, first: usize, second: usize) {
+        self.iter_mut()
+            .for_each(|column| column.swap(first, second));
+    }
+}
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Applies the permutation to all columns, walking the cycles once.
+        ///
+        /// It fails if any column does not have length `SIZE`.
+        pub fn apply_columns<C>(&self, mut columns: C) -> Result<(), PermError>
+        where
+            C: PermColumns,
+        {
+            columns.check_len(SIZE)?;
+            swap_cycles(&self.indices, &mut [false; SIZE], false, |src, dst| {
+                columns.swap_rows(src, dst)
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{apply::PermApply, from_indices::PermFromIndices};
+
+        #[test]
+        fn static_apply_columns() {
+            let perm = PermS::from_indices([2, 0, 3, 1]).unwrap();
+            let mut positions = [0.0, 1.0, 2.0, 3.0];
+            let mut ids = ["a", "b", "c", "d"];
+            perm.apply_columns((&mut positions[..], &mut ids[..]))
+                .unwrap();
+            assert_eq!(positions, [2.0, 0.0, 3.0, 1.0]);
+            assert_eq!(ids, ["c", "a", "d", "b"]);
+
+            let mut first = [0, 1, 2, 3];
+            let mut second = [10, 11, 12, 13];
+            perm.apply_columns([&mut first[..], &mut second[..]])
+                .unwrap();
+            let mut expect = [10, 11, 12, 13];
+            perm.apply(&mut expect);
+            assert_eq!(second, expect);
+
+            let mut short = [0; 3];
+            assert_eq!(
+                perm.apply_columns((&mut first[..], &mut second[..], &mut short[..])),
+                Err(PermError::LengthMismatch {
+                    expected: 4,
+                    actual: 3
+                })
+            );
+            assert_eq!(first, [2, 0, 3, 1]);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{common::*, perm_type::PermD};
+
+    impl<T> PermColumns for Vec<&mut [T]> {
+        fn check_len(&self, len: usize) -> Result<(), PermError> {
+            self.iter()
+                .try_for_each(|column| check_len(len, column.len()))
+        }
+
+        fn swap_rows(&mut self, first: usize, second: usize) {
+            self.iter_mut()
+                .for_each(|column| column.swap(first, second));
+        }
+    }
+
+    impl PermD {
+        /// Applies the permutation to all columns, walking the cycles once.
+        ///
+        /// It fails if any column does not have the permutation's length.
+        pub fn apply_columns<C>(&self, mut columns: C) -> Result<(), PermError>
+        where
+            C: PermColumns,
+        {
+            let len = self.indices.len();
+            columns.check_len(len)?;
+            swap_cycles(&self.indices, &mut vec![false; len], false, |src, dst| {
+                columns.swap_rows(src, dst)
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::apply::PermApply;
+        use rand::prelude::*;
+
+        #[test]
+        fn dynamic_apply_columns() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(500);
+            perm.indices.shuffle(&mut rng);
+
+            let mut positions: Vec<f32> = (0..500).map(|_| rng.gen()).collect();
+            let mut velocities: Vec<f64> = (0..500).map(|_| rng.gen()).collect();
+            let mut ids: Vec<usize> = (0..500).collect();
+            let mut expect = (positions.clone(), velocities.clone(), ids.clone());
+            perm.apply(&mut expect.0).unwrap();
+            perm.apply(&mut expect.1).unwrap();
+            perm.apply(&mut expect.2).unwrap();
+
+            perm.apply_columns((&mut positions[..], &mut velocities[..], &mut ids[..]))
+                .unwrap();
+            assert_eq!((positions, velocities, ids), expect);
+
+            let mut columns: Vec<Vec<u8>> = (0..5)
+                .map(|_| (0..500).map(|_| rng.gen()).collect())
+                .collect();
+            let mut expect = columns.clone();
+            expect
+                .iter_mut()
+                .for_each(|column| perm.apply(column).unwrap());
+            let mut slices: Vec<_> = columns
+                .iter_mut()
+                .map(|column| column.as_mut_slice())
+                .collect();
+            perm.apply_columns(&mut slices[..]).unwrap();
+            perm.apply_columns(slices).unwrap();
+            expect
+                .iter_mut()
+                .for_each(|column| perm.apply(column).unwrap());
+            assert_eq!(columns, expect);
+
+            let mut short = vec![0; 499];
+            assert!(PermD::identity(500)
+                .apply_columns((&mut expect[0][..], &mut short[..]))
+                .is_err());
+        }
+    }
+}
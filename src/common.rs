@@ -1,18 +1,15 @@
-#[cfg(feature = "std")]
-pub use std::{
-    borrow::Cow,
-    cmp::Ordering,
-    convert::{TryFrom, TryInto},
-    iter::{self, Product},
-    mem,
-    ops::Mul,
-};
-
-#[cfg(not(feature = "std"))]
 pub use core::{
+    array,
     cmp::Ordering,
-    convert::{TryFrom, TryInto},
+    convert::TryInto,
+    fmt::{self, Display, Formatter},
     iter::{self, Product},
     mem,
     ops::Mul,
+    str::FromStr,
 };
+
+#[cfg(feature = "alloc")]
+pub use alloc::{borrow::Cow, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+pub use core::convert::TryFrom;
@@ -0,0 +1,211 @@
+use crate::cycles::sorted_cycle_starts;
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Checks if the permutation is conjugate to the other one, that is, both have the same cycle type.
+        pub fn is_conjugate(&self, other: &PermS<SIZE>) -> bool {
+            self.cycle_type() == other.cycle_type()
+        }
+
+        /// Finds a permutation `g` with `g⁻¹ · self · g = other`, such that `self.conjugate_with(&g)` equals `other`.
+        ///
+        /// It returns `None` if the permutations are not conjugate.
+        pub fn find_conjugator(&self, other: &PermS<SIZE>) -> Option<Self> {
+            let mut conjugator = Self::identity();
+            let found = conjugate_cycles(
+                &self.indices,
+                &other.indices,
+                &mut conjugator.indices,
+                [
+                    &mut [0; SIZE],
+                    &mut [0; SIZE],
+                    &mut [0; SIZE],
+                    &mut [0; SIZE],
+                ],
+            );
+            found.then_some(conjugator)
+        }
+
+        /// Gets the canonical representative of the conjugacy class, where the cycles are
+        /// laid out as consecutive points `(0 1 .. k-1)(k ..)` in descending lengths.
+        pub fn class_representative(&self) -> Self {
+            let mut perm = Self::identity();
+            representative(&self.cycle_type(), &mut perm.indices);
+            perm
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::from_indices::PermFromIndices;
+
+        #[test]
+        fn static_conjugacy() {
+            // (0 2 4)(1 3) and (1 4 5)(0 2)
+            let lhs = PermS::from_indices([2, 3, 4, 1, 0, 5]).unwrap();
+            let rhs = PermS::from_indices([2, 4, 0, 3, 5, 1]).unwrap();
+            assert!(lhs.is_conjugate(&rhs));
+
+            let conjugator = lhs.find_conjugator(&rhs).unwrap();
+            assert_eq!(lhs.conjugate_with(&conjugator), rhs);
+            assert_eq!(
+                lhs.class_representative(),
+                PermS::from_indices([1, 2, 0, 4, 3, 5]).unwrap()
+            );
+            assert_eq!(lhs.class_representative(), rhs.class_representative());
+
+            let other = PermS::<6>::swap(0, 5).unwrap();
+            assert!(!lhs.is_conjugate(&other));
+            assert!(lhs.find_conjugator(&other).is_none());
+
+            (0..120).for_each(|rank| {
+                let perm = PermS::<5>::from_rank(rank).unwrap();
+                let representative = perm.class_representative();
+                let conjugator = perm.find_conjugator(&representative).unwrap();
+                assert_eq!(perm.conjugate_with(&conjugator), representative);
+                assert_eq!(representative.class_representative(), representative);
+            });
+
+            assert_eq!(
+                PermS::<0>::identity().find_conjugator(&PermS::identity()),
+                Some(PermS::identity())
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{common::*, perm_type::PermD};
+
+    impl PermD {
+        /// Checks if the permutation is conjugate to the other one, that is, both have the same size and cycle type.
+        pub fn is_conjugate(&self, other: &PermD) -> bool {
+            self.cycle_type() == other.cycle_type()
+        }
+
+        /// Finds a permutation `g` with `g⁻¹ · self · g = other`, such that `self.conjugate_with(&g)` equals `other`.
+        ///
+        /// It returns `None` if the permutations are not conjugate.
+        pub fn find_conjugator(&self, other: &PermD) -> Option<Self> {
+            let len = self.indices.len();
+            if len != other.indices.len() {
+                return None;
+            }
+
+            let mut conjugator = Self::identity(len);
+            let found = conjugate_cycles(
+                &self.indices,
+                &other.indices,
+                &mut conjugator.indices,
+                [
+                    &mut vec![0; len],
+                    &mut vec![0; len],
+                    &mut vec![0; len],
+                    &mut vec![0; len],
+                ],
+            );
+            found.then_some(conjugator)
+        }
+
+        /// Gets the canonical representative of the conjugacy class, where the cycles are
+        /// laid out as consecutive points `(0 1 .. k-1)(k ..)` in descending lengths.
+        pub fn class_representative(&self) -> Self {
+            let mut perm = Self::identity(self.indices.len());
+            representative(&self.cycle_type(), &mut perm.indices);
+            perm
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rand::prelude::*;
+
+        #[test]
+        fn dynamic_conjugacy() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1000);
+            perm.indices.shuffle(&mut rng);
+            let mut relabel = PermD::identity(1000);
+            relabel.indices.shuffle(&mut rng);
+            let other = perm.conjugate_with(&relabel).unwrap();
+
+            assert!(perm.is_conjugate(&other));
+            let conjugator = perm.find_conjugator(&other).unwrap();
+            assert_eq!(perm.conjugate_with(&conjugator).unwrap(), other);
+            assert_eq!(perm.class_representative(), other.class_representative());
+
+            assert!(!PermD::cycle(4).is_conjugate(&PermD::cycle(5)));
+            assert!(PermD::cycle(4)
+                .find_conjugator(&PermD::swap(4, 0, 1).unwrap())
+                .is_none());
+            assert!(PermD::cycle(4).find_conjugator(&PermD::cycle(5)).is_none());
+            assert_eq!(
+                PermD::swap(4, 1, 3).unwrap().class_representative(),
+                PermD::swap(4, 0, 1).unwrap()
+            );
+        }
+    }
+}
+
+/// Writes the conjugator mapping the cycles of `rhs` onto the cycles of `lhs` with the same lengths.
+///
+/// It returns false if the cycle types differ.
+fn conjugate_cycles(
+    lhs: &[usize],
+    rhs: &[usize],
+    conjugator: &mut [usize],
+    [lhs_lens, lhs_starts, rhs_lens, rhs_starts]: [&mut [usize]; 4],
+) -> bool {
+    let num_cycles = sorted_cycle_starts(lhs, lhs_lens, lhs_starts);
+    if sorted_cycle_starts(rhs, rhs_lens, rhs_starts) != num_cycles {
+        return false;
+    }
+
+    let lhs_starts = &lhs_starts[..num_cycles];
+    let rhs_starts = &rhs_starts[..num_cycles];
+    let same_type = lhs_starts
+        .iter()
+        .zip(rhs_starts)
+        .all(|(&lhs_start, &rhs_start)| lhs_lens[lhs_start] == rhs_lens[rhs_start]);
+    if !same_type {
+        return false;
+    }
+
+    // g · rhs = lhs · g holds if g maps each cycle of rhs along a cycle of lhs
+    lhs_starts
+        .iter()
+        .zip(rhs_starts)
+        .for_each(|(&lhs_start, &rhs_start)| {
+            let mut from = rhs_start;
+            let mut to = lhs_start;
+            loop {
+                conjugator[from] = to;
+                from = rhs[from];
+                to = lhs[to];
+                if from == rhs_start {
+                    break;
+                }
+            }
+        });
+    true
+}
+
+/// Writes the permutation laying out cycles of given lengths on consecutive points.
+fn representative(cycle_type: &[usize], indices: &mut [usize]) {
+    let mut offset = 0;
+    cycle_type
+        .iter()
+        .take_while(|&&len| len > 0)
+        .for_each(|&len| {
+            (offset..(offset + len)).for_each(|point| indices[point] = point + 1);
+            indices[offset + len - 1] = offset;
+            offset += len;
+        });
+}
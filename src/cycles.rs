@@ -0,0 +1,280 @@
+use crate::common::*;
+
+/// An iterator over the disjoint cycles of a permutation.
+///
+/// Each cycle starts from its smallest element, and the cycles are ordered
+/// by their smallest elements. The cycle containing `index` follows
+/// `index -> indices[index]`.
+#[derive(Debug, Clone)]
+pub struct Cycles<'a, V>
+where
+    V: AsMut<[bool]>,
+{
+    indices: &'a [usize],
+    visited: V,
+    next: usize,
+    skip_fixed_points: bool,
+}
+
+impl<'a, V> Cycles<'a, V>
+where
+    V: AsMut<[bool]>,
+{
+    /// Creates the iterator over the cycles of indices, using `visited` as the buffer of visited flags.
+    ///
+    /// It is useful for implementing [cycles](crate::Permutation::cycles) on custom permutation types.
+    /// The flags are cleared before iterating.
+    ///
+    /// # Panics
+    /// It panics if `indices` and `visited` have different lengths.
+    pub fn new(indices: &'a [usize], mut visited: V) -> Self {
+        assert_eq!(indices.len(), visited.as_mut().len());
+        visited.as_mut().iter_mut().for_each(|flag| *flag = false);
+
+        Self {
+            indices,
+            visited,
+            next: 0,
+            skip_fixed_points: false,
+        }
+    }
+
+    /// Skips cycles of length 1.
+    pub fn skip_fixed_points(mut self) -> Self {
+        self.skip_fixed_points = true;
+        self
+    }
+}
+
+impl<'a, V> Iterator for Cycles<'a, V>
+where
+    V: AsMut<[bool]>,
+{
+    type Item = Cycle<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices;
+        let visited = self.visited.as_mut();
+
+        while self.next < indices.len() {
+            let start = self.next;
+            self.next += 1;
+
+            if visited[start] {
+                continue;
+            }
+
+            let mut len = 0;
+            let mut curr = start;
+            loop {
+                visited[curr] = true;
+                len += 1;
+                curr = indices[curr];
+                if curr == start {
+                    break;
+                }
+            }
+
+            if self.skip_fixed_points && len == 1 {
+                continue;
+            }
+
+            return Some(Cycle {
+                indices,
+                start,
+                curr: start,
+                remaining: len,
+            });
+        }
+
+        None
+    }
+}
+
+/// A single cycle of a permutation, iterating over its elements from the smallest one.
+#[derive(Debug, Clone)]
+pub struct Cycle<'a> {
+    indices: &'a [usize],
+    start: usize,
+    curr: usize,
+    remaining: usize,
+}
+
+impl<'a> Cycle<'a> {
+    /// Gets the smallest element of the cycle.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+}
+
+impl<'a> Iterator for Cycle<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let curr = self.curr;
+        self.curr = self.indices[curr];
+        self.remaining -= 1;
+        Some(curr)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Cycle<'a> {}
+
+impl<'a> iter::FusedIterator for Cycle<'a> {}
+
+impl<'a, V> iter::FusedIterator for Cycles<'a, V> where V: AsMut<[bool]> {}
+
+/// Writes the cycle starts sorted by cycle lengths and returns the number of cycles.
+///
+/// The length of the cycle starting from `start` is written to `lens[start]`.
+pub(crate) fn sorted_cycle_starts(
+    indices: &[usize],
+    lens: &mut [usize],
+    starts: &mut [usize],
+) -> usize {
+    lens.iter_mut().for_each(|len| *len = 0);
+    let mut num_cycles = 0;
+
+    (0..indices.len()).for_each(|start| {
+        // visited elements other than starts are marked with a non-zero length
+        if lens[start] != 0 {
+            return;
+        }
+
+        let mut len = 0;
+        let mut curr = start;
+        loop {
+            lens[curr] = 1;
+            len += 1;
+            curr = indices[curr];
+            if curr == start {
+                break;
+            }
+        }
+        lens[start] = len;
+        starts[num_cycles] = start;
+        num_cycles += 1;
+    });
+
+    starts[..num_cycles].sort_unstable_by_key(|&start| (lens[start], start));
+    num_cycles
+}
+
+mod without_std {
+    use crate::{perm_trait::Permutation, perm_type::PermS};
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Gets the cycle type, the cycle lengths in descending order padded with zeros.
+        pub fn cycle_type(&self) -> [usize; SIZE] {
+            let mut lengths = [0; SIZE];
+            self.cycles()
+                .zip(lengths.iter_mut())
+                .for_each(|(cycle, len)| *len = cycle.len());
+            lengths.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+            lengths
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use crate::{common::*, perm_trait::Permutation, perm_type::PermD};
+
+    impl PermD {
+        /// Gets the cycle type, the cycle lengths in descending order.
+        pub fn cycle_type(&self) -> Vec<usize> {
+            let mut lengths: Vec<_> = self.cycles().map(|cycle| cycle.len()).collect();
+            lengths.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+            lengths
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_indices::PermFromIndices, perm_trait::Permutation, perm_type::PermS};
+
+    #[test]
+    fn static_cycles() {
+        let perm = PermS::from_indices([2, 3, 4, 1, 0, 5]).unwrap();
+
+        let mut cycles = perm.cycles();
+        let mut buf = [0; 6];
+
+        let cycle = cycles.next().unwrap();
+        assert_eq!(cycle.start(), 0);
+        assert_eq!(cycle.len(), 3);
+        cycle
+            .zip(buf.iter_mut())
+            .for_each(|(index, slot)| *slot = index);
+        assert_eq!(&buf[..3], &[0, 2, 4]);
+
+        let cycle = cycles.next().unwrap();
+        cycle
+            .zip(buf.iter_mut())
+            .for_each(|(index, slot)| *slot = index);
+        assert_eq!(&buf[..2], &[1, 3]);
+
+        let cycle = cycles.next().unwrap();
+        assert_eq!(cycle.start(), 5);
+        assert_eq!(cycle.len(), 1);
+
+        assert!(cycles.next().is_none());
+
+        assert_eq!(perm.cycles().skip_fixed_points().count(), 2);
+        assert_eq!(PermS::<6>::identity().cycles().count(), 6);
+        assert_eq!(
+            PermS::<6>::identity().cycles().skip_fixed_points().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn static_cycle_type() {
+        let perm = PermS::from_indices([2, 3, 4, 1, 0, 5]).unwrap();
+        assert_eq!(perm.cycle_type(), [3, 2, 1, 0, 0, 0]);
+        assert_eq!(PermS::<4>::identity().cycle_type(), [1, 1, 1, 1]);
+        assert_eq!(PermS::<0>::identity().cycle_type(), []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dynamic_cycles() {
+        use crate::perm_type::PermD;
+        use rand::prelude::*;
+
+        assert_eq!(PermD::empty().cycles().count(), 0);
+
+        let cycles: Vec<Vec<usize>> = PermD::cycle(4).cycles().map(|c| c.collect()).collect();
+        assert_eq!(cycles, vec![vec![0, 3, 2, 1]]);
+
+        let mut rng = rand::thread_rng();
+        let mut perm = PermD::identity(1024);
+        perm.indices.shuffle(&mut rng);
+
+        let mut covered = vec![false; 1024];
+        perm.cycles().for_each(|cycle| {
+            let start = cycle.start();
+            let elements: Vec<_> = cycle.collect();
+            assert_eq!(elements.iter().min(), Some(&start));
+            elements.iter().enumerate().for_each(|(nth, &index)| {
+                assert!(!covered[index]);
+                covered[index] = true;
+                assert_eq!(perm.indices[index], elements[(nth + 1) % elements.len()]);
+            });
+        });
+        assert!(covered.into_iter().all(|flag| flag));
+
+        let cycle_type = perm.cycle_type();
+        assert_eq!(cycle_type.iter().sum::<usize>(), 1024);
+        assert!(cycle_type.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(PermD::swap(5, 0, 4).unwrap().cycle_type(), vec![2, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{
+    common::*, fenwick::Fenwick, perm_trait::Permutation, perm_type::Perm, size::PermSize,
+};
+
+/// The distance operator between two permutations of the same size.
+pub trait PermDistance<Rhs> {
+    type Output;
+
+    fn distance(&self, other: &Rhs) -> Self::Output;
+}
+
+/// The distances between two permutations, viewed as rankings that map each item to its rank.
+///
+/// All metrics are computed from the relative permutation `other · self⁻¹`, which maps
+/// the ranks by `self` to the ranks by `other`.
+pub struct Distance<S>
+where
+    S: PermSize,
+{
+    relative: Perm<S>,
+}
+
+impl<S> Distance<S>
+where
+    S: PermSize,
+    S::Container: Clone,
+    Perm<S>: Permutation,
+{
+    /// Gets the relative permutation `other · self⁻¹`.
+    pub fn relative(&self) -> &Perm<S> {
+        &self.relative
+    }
+
+    /// Gets the Kendall tau distance, the number of item pairs ordered differently by both rankings.
+    ///
+    /// It takes O(n log n) time.
+    pub fn kendall_tau(&self) -> usize {
+        let indices = self.relative.indices.as_ref();
+        let mut tree = self.relative.scratch();
+        let mut inserted = Fenwick::empty(tree.as_mut());
+
+        // count the larger indices to the left of each index
+        indices
+            .iter()
+            .enumerate()
+            .map(|(position, &index)| {
+                let larger = position - inserted.count_less(index);
+                inserted.insert(index);
+                larger
+            })
+            .sum()
+    }
+
+    /// Gets the Kendall tau correlation coefficient, which is 1 for identical rankings and -1 for reversed rankings.
+    ///
+    /// It is 1 for permutations with fewer than two items.
+    pub fn kendall_correlation(&self) -> f64 {
+        let len = self.relative.len() as f64;
+        match self.relative.len() {
+            0 | 1 => 1.0,
+            _ => 1.0 - 4.0 * self.kendall_tau() as f64 / (len * (len - 1.0)),
+        }
+    }
+
+    /// Gets the Cayley distance, the least number of transpositions turning one ranking into the other.
+    pub fn cayley(&self) -> usize {
+        self.relative.len() - self.relative.cycles().count()
+    }
+
+    /// Gets the Hamming distance, the number of items with different ranks.
+    pub fn hamming(&self) -> usize {
+        displacements(self.relative.indices.as_ref())
+            .filter(|&diff| diff != 0)
+            .count()
+    }
+
+    /// Gets the Spearman footrule, the sum of absolute rank differences.
+    pub fn footrule(&self) -> usize {
+        displacements(self.relative.indices.as_ref()).sum()
+    }
+
+    /// Gets the Spearman rho distance, the sum of squared rank differences.
+    pub fn rho(&self) -> u128 {
+        displacements(self.relative.indices.as_ref())
+            .map(|diff| diff as u128 * diff as u128)
+            .sum()
+    }
+
+    /// Gets the Ulam distance, the least number of items to move for turning one ranking into the other.
+    ///
+    /// It is the size minus the longest increasing subsequence of the relative permutation,
+    /// and takes O(n log n) time.
+    pub fn ulam(&self) -> usize {
+        let indices = self.relative.indices.as_ref();
+        let mut tails = self.relative.scratch();
+        let tails = tails.as_mut();
+        let mut longest = 0;
+
+        // tails[k] is the least tail of increasing subsequences of length k + 1
+        indices.iter().for_each(|&index| {
+            let slot = tails[..longest].partition_point(|&tail| tail < index);
+            tails[slot] = index;
+            if slot == longest {
+                longest += 1;
+            }
+        });
+
+        indices.len() - longest
+    }
+}
+
+impl<S> fmt::Debug for Distance<S>
+where
+    S: PermSize,
+    Perm<S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Distance")
+            .field("relative", &self.relative)
+            .finish()
+    }
+}
+
+impl<S> Clone for Distance<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            relative: self.relative.clone(),
+        }
+    }
+}
+
+mod without_std {
+    use super::*;
+    use crate::{perm_type::PermS, size::Static};
+
+    impl<const SIZE: usize> PermDistance<PermS<SIZE>> for PermS<SIZE> {
+        type Output = Distance<Static<SIZE>>;
+
+        fn distance(&self, other: &PermS<SIZE>) -> Self::Output {
+            Distance {
+                relative: other * &self.inverse(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::from_indices::PermFromIndices;
+
+        #[test]
+        fn static_distance() {
+            let identity = PermS::<4>::identity();
+            let distance = identity.distance(&PermS::from_indices([3, 2, 1, 0]).unwrap());
+            assert_eq!(distance.kendall_tau(), 6);
+            assert_eq!(distance.kendall_correlation(), -1.0);
+            assert_eq!(distance.cayley(), 2);
+            assert_eq!(distance.hamming(), 4);
+            assert_eq!(distance.footrule(), 8);
+            assert_eq!(distance.rho(), 20);
+            assert_eq!(distance.ulam(), 3);
+
+            // moving the first item to the end
+            let distance = identity.distance(&PermS::from_indices([3, 0, 1, 2]).unwrap());
+            assert_eq!(distance.kendall_tau(), 3);
+            assert_eq!(distance.cayley(), 3);
+            assert_eq!(distance.ulam(), 1);
+
+            let distance = identity.distance(&identity);
+            assert_eq!(distance.kendall_tau(), 0);
+            assert_eq!(distance.kendall_correlation(), 1.0);
+            assert_eq!(distance.ulam(), 0);
+            assert_eq!(distance.relative(), &identity);
+
+            // all metrics are invariant under relabeling items
+            let lhs = PermS::from_indices([2, 0, 4, 1, 3]).unwrap();
+            let rhs = PermS::from_indices([1, 4, 0, 3, 2]).unwrap();
+            let relabel = PermS::from_indices([4, 2, 0, 3, 1]).unwrap();
+            let distance = lhs.distance(&rhs);
+            let relabeled = (&lhs * &relabel).distance(&(&rhs * &relabel));
+            assert_eq!(distance.kendall_tau(), relabeled.kendall_tau());
+            assert_eq!(distance.footrule(), relabeled.footrule());
+            assert_eq!(distance.ulam(), relabeled.ulam());
+            assert_eq!(distance.cayley(), relabeled.cayley());
+            assert_eq!(
+                PermS::<0>::identity()
+                    .distance(&PermS::identity())
+                    .kendall_correlation(),
+                1.0
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{
+        error::{check_len, PermError},
+        perm_type::{PermD, PermS},
+        size::{Dynamic, Static},
+    };
+
+    impl<const SIZE: usize> PermDistance<PermD> for PermS<SIZE> {
+        type Output = Result<Distance<Static<SIZE>>, PermError>;
+
+        fn distance(&self, other: &PermD) -> Self::Output {
+            check_len(SIZE, other.len())?;
+            Ok(Distance {
+                relative: other * &self.inverse(),
+            })
+        }
+    }
+
+    impl<const SIZE: usize> PermDistance<PermS<SIZE>> for PermD {
+        type Output = Result<Distance<Static<SIZE>>, PermError>;
+
+        fn distance(&self, other: &PermS<SIZE>) -> Self::Output {
+            check_len(self.len(), SIZE)?;
+            Ok(Distance {
+                relative: other * &self.inverse(),
+            })
+        }
+    }
+
+    impl PermDistance<PermD> for PermD {
+        type Output = Result<Distance<Dynamic>, PermError>;
+
+        fn distance(&self, other: &PermD) -> Self::Output {
+            check_len(self.len(), other.len())?;
+            Ok(Distance {
+                relative: other * &self.inverse(),
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{from_indices::PermFromIndices, product::PermProduct};
+        use rand::prelude::*;
+
+        #[test]
+        fn dynamic_distance() {
+            let mut rng = rand::thread_rng();
+            let mut lhs = PermD::identity(200);
+            let mut rhs = PermD::identity(200);
+            lhs.indices.shuffle(&mut rng);
+            rhs.indices.shuffle(&mut rng);
+            let distance = lhs.distance(&rhs).unwrap();
+
+            let pairs = (0..200).flat_map(|first| (0..first).map(move |second| (first, second)));
+            let discordant = pairs
+                .filter(|&(first, second)| {
+                    (lhs.indices[first] < lhs.indices[second])
+                        != (rhs.indices[first] < rhs.indices[second])
+                })
+                .count();
+            assert_eq!(distance.kendall_tau(), discordant);
+
+            let diffs = lhs
+                .indices
+                .iter()
+                .zip(&rhs.indices)
+                .map(|(&lhs, &rhs)| (lhs as i64 - rhs as i64).abs());
+            assert_eq!(distance.footrule() as i64, diffs.clone().sum::<i64>());
+            assert_eq!(
+                distance.rho() as i64,
+                diffs.clone().map(|diff| diff * diff).sum::<i64>()
+            );
+            assert_eq!(distance.hamming(), diffs.filter(|&diff| diff != 0).count());
+            assert_eq!(
+                distance.cayley(),
+                200 - lhs.inverse().perm_product(&rhs).unwrap().cycles().count()
+            );
+            assert!(distance.ulam() <= distance.cayley());
+
+            let kendall = rhs.distance(&lhs).unwrap().kendall_tau();
+            assert_eq!(kendall, distance.kendall_tau());
+
+            let lhs = PermS::from_indices([1, 2, 0]).unwrap();
+            let rhs = PermD::from_indices(vec![0, 2, 1]).unwrap();
+            assert_eq!(lhs.distance(&rhs).unwrap().kendall_tau(), 1);
+            assert_eq!(rhs.distance(&lhs).unwrap().kendall_tau(), 1);
+            assert_eq!(
+                lhs.distance(&PermD::identity(4)).unwrap_err(),
+                PermError::LengthMismatch {
+                    expected: 3,
+                    actual: 4
+                }
+            );
+            assert!(PermD::identity(2).distance(&PermD::identity(3)).is_err());
+        }
+    }
+}
+
+/// Iterates over the absolute differences between each index and its position.
+fn displacements(indices: &[usize]) -> impl Iterator<Item = usize> + '_ {
+    indices
+        .iter()
+        .enumerate()
+        .map(|(position, &index)| index.abs_diff(position))
+}
@@ -0,0 +1,538 @@
+use crate::{
+    common::*,
+    from_sorting::PermFromSorting,
+    perm_type::Perm,
+    size::{PermSize, Static},
+};
+
+impl<S> Perm<S>
+where
+    S: PermSize,
+{
+    /// Advances to the next permutation in lexicographic order in-place.
+    ///
+    /// It returns `false` and wraps around to the identity if the permutation is the last one.
+    pub fn next_permutation(&mut self) -> bool {
+        step_permutation(self.indices.as_mut(), Ordering::Less, Ord::cmp)
+    }
+
+    /// Steps back to the previous permutation in lexicographic order in-place.
+    ///
+    /// It returns `false` and wraps around to the last one if the permutation is the identity.
+    pub fn prev_permutation(&mut self) -> bool {
+        step_permutation(self.indices.as_mut(), Ordering::Greater, Ord::cmp)
+    }
+
+    /// Advances to the next k-permutation in lexicographic order of the first `k` indices in-place.
+    ///
+    /// The indices after the first `k` are expected to be ascending, and are kept ascending.
+    /// It returns `false` and wraps around to the identity if the permutation is the last one.
+    /// It panics if `k` exceeds the size.
+    pub fn next_k_permutation(&mut self, k: usize) -> bool {
+        let indices = self.indices.as_mut();
+
+        // the reversed suffix is the last arrangement after the prefix
+        indices[k..].reverse();
+        step_permutation(indices, Ordering::Less, Ord::cmp)
+    }
+}
+
+/// An iterator over all permutations of a size in lexicographic order, starting from the identity.
+///
+/// Each step advances an internal permutation with
+/// [next_permutation](crate::Perm::next_permutation) and yields a copy of it.
+pub struct AllPerms<S>
+where
+    S: PermSize,
+{
+    next: Option<Perm<S>>,
+}
+
+impl<S> fmt::Debug for AllPerms<S>
+where
+    S: PermSize,
+    Perm<S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AllPerms")
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl<S> Clone for AllPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next.clone(),
+        }
+    }
+}
+
+impl<S> Iterator for AllPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+    type Item = Perm<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let perm = self.next.as_mut()?;
+        let item = perm.clone();
+        if !perm.next_permutation() {
+            self.next = None;
+        }
+        Some(item)
+    }
+}
+
+impl<S> iter::FusedIterator for AllPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+}
+
+/// An iterator over the k-permutations of a size in lexicographic order, starting from the identity.
+///
+/// Each k-permutation is yielded as a permutation whose first `k` indices are the chosen
+/// indices and the remaining indices are ascending. Applying it moves the chosen elements to the front.
+pub struct KPerms<S>
+where
+    S: PermSize,
+{
+    next: Option<Perm<S>>,
+    k: usize,
+}
+
+impl<S> fmt::Debug for KPerms<S>
+where
+    S: PermSize,
+    Perm<S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KPerms")
+            .field("next", &self.next)
+            .field("k", &self.k)
+            .finish()
+    }
+}
+
+impl<S> Clone for KPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next.clone(),
+            k: self.k,
+        }
+    }
+}
+
+impl<S> Iterator for KPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+    type Item = Perm<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let perm = self.next.as_mut()?;
+        let item = perm.clone();
+        if !perm.next_k_permutation(self.k) {
+            self.next = None;
+        }
+        Some(item)
+    }
+}
+
+impl<S> iter::FusedIterator for KPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+}
+
+/// An iterator over the distinct arrangements of a multiset in lexicographic order.
+///
+/// Each item is a permutation that arranges the input elements, starting from the sorted
+/// arrangement. Elements comparing equal are indistinguishable, so no arrangement is repeated.
+pub struct MultisetPerms<S>
+where
+    S: PermSize,
+{
+    next: Option<Perm<S>>,
+    // the rank of equivalence class of each element
+    classes: S::Container,
+}
+
+impl<S> fmt::Debug for MultisetPerms<S>
+where
+    S: PermSize,
+    S::Container: fmt::Debug,
+    Perm<S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultisetPerms")
+            .field("next", &self.next)
+            .field("classes", &self.classes)
+            .finish()
+    }
+}
+
+impl<S> Clone for MultisetPerms<S>
+where
+    S: PermSize,
+    S::Container: Clone,
+    Perm<S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next.clone(),
+            classes: self.classes.clone(),
+        }
+    }
+}
+
+impl<S> MultisetPerms<S>
+where
+    S: PermSize,
+{
+    fn new<T, F>(sorted: Perm<S>, mut classes: S::Container, values: &[T], mut compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let indices = sorted.indices.as_ref();
+        let classes_mut = classes.as_mut();
+
+        // number the classes in sorted order
+        let mut class = 0;
+        indices.iter().enumerate().for_each(|(position, &index)| {
+            if position > 0 && compare(&values[indices[position - 1]], &values[index]).is_ne() {
+                class += 1;
+            }
+            classes_mut[index] = class;
+        });
+
+        Self {
+            next: Some(sorted),
+            classes,
+        }
+    }
+}
+
+impl<S> Iterator for MultisetPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+    type Item = Perm<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let perm = self.next.as_mut()?;
+        let item = perm.clone();
+        let classes = self.classes.as_ref();
+
+        if !step_permutation(perm.indices.as_mut(), Ordering::Less, |lhs, rhs| {
+            classes[*lhs].cmp(&classes[*rhs])
+        }) {
+            self.next = None;
+        }
+        Some(item)
+    }
+}
+
+impl<S> iter::FusedIterator for MultisetPerms<S>
+where
+    S: PermSize,
+    Perm<S>: Clone,
+{
+}
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Iterates over all static permutations in lexicographic order.
+        pub fn all() -> AllPerms<Static<SIZE>> {
+            AllPerms {
+                next: Some(Self::identity()),
+            }
+        }
+
+        /// Iterates over the static k-permutations in lexicographic order, which is empty if `k` exceeds `SIZE`.
+        pub fn k_perms(k: usize) -> KPerms<Static<SIZE>> {
+            KPerms {
+                next: (k <= SIZE).then(Self::identity),
+                k,
+            }
+        }
+
+        /// Iterates over the distinct arrangements of an array.
+        pub fn multiset_perms<T>(values: &[T; SIZE]) -> MultisetPerms<Static<SIZE>>
+        where
+            T: Ord,
+        {
+            Self::multiset_perms_by(values, T::cmp)
+        }
+
+        /// Iterates over the distinct arrangements of an array, where elements are compared by a comparing function.
+        pub fn multiset_perms_by<T, F>(
+            values: &[T; SIZE],
+            mut compare: F,
+        ) -> MultisetPerms<Static<SIZE>>
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            let sorted = Self::from_sort_by(values, &mut compare);
+            MultisetPerms::new(sorted, [0; SIZE], values, compare)
+        }
+
+        /// Iterates over the distinct arrangements of an array, where elements with equal keys are indistinguishable.
+        pub fn multiset_perms_by_key<T, B, F>(
+            values: &[T; SIZE],
+            mut f: F,
+        ) -> MultisetPerms<Static<SIZE>>
+        where
+            B: Ord,
+            F: FnMut(&T) -> B,
+        {
+            Self::multiset_perms_by(values, |lhs, rhs| f(lhs).cmp(&f(rhs)))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{apply::PermApply, from_indices::PermFromIndices};
+
+        #[test]
+        fn static_all() {
+            assert_eq!(PermS::<0>::all().count(), 1);
+            assert_eq!(PermS::<1>::all().count(), 1);
+
+            let mut count = 0;
+            PermS::<5>::all().enumerate().for_each(|(rank, perm)| {
+                assert_eq!(perm.rank().unwrap(), rank as u128);
+                count += 1;
+            });
+            assert_eq!(count, 120);
+
+            let mut perm = PermS::from_indices([0, 2, 1]).unwrap();
+            assert!(perm.next_permutation());
+            assert_eq!(perm, PermS::from_indices([1, 0, 2]).unwrap());
+            assert!(perm.prev_permutation());
+            assert!(perm.prev_permutation());
+            assert_eq!(perm, PermS::from_indices([0, 1, 2]).unwrap());
+            assert!(!perm.prev_permutation());
+            assert_eq!(perm, PermS::from_indices([2, 1, 0]).unwrap());
+            assert!(!perm.next_permutation());
+            assert_eq!(perm, PermS::<3>::identity());
+        }
+
+        #[test]
+        fn static_k_perms() {
+            let mut count = 0;
+            let mut prev: Option<PermS<5>> = None;
+            PermS::<5>::k_perms(2).for_each(|perm| {
+                assert!(perm.indices[2..].windows(2).all(|pair| pair[0] < pair[1]));
+                if let Some(prev) = &prev {
+                    assert!(prev.indices[..2] < perm.indices[..2]);
+                }
+                prev = Some(perm);
+                count += 1;
+            });
+            assert_eq!(count, 20);
+
+            assert_eq!(PermS::<5>::k_perms(0).count(), 1);
+            assert!(PermS::<5>::k_perms(5).eq(PermS::<5>::all()));
+            assert!(PermS::<5>::k_perms(4).eq(PermS::<5>::all()));
+            assert_eq!(PermS::<5>::k_perms(6).count(), 0);
+
+            let mut array = ['a', 'b', 'c', 'd'];
+            let perm = PermS::<4>::k_perms(2).nth(5).unwrap();
+            perm.apply(&mut array);
+            assert_eq!(&array[..2], &['b', 'd']);
+        }
+
+        #[test]
+        fn static_multiset_perms() {
+            let values = [2, 1, 2, 1];
+            let mut arrangements = PermS::multiset_perms(&values).map(|perm| {
+                let mut array = values;
+                perm.apply(&mut array);
+                array
+            });
+            assert_eq!(arrangements.next(), Some([1, 1, 2, 2]));
+            assert_eq!(arrangements.next(), Some([1, 2, 1, 2]));
+            assert_eq!(arrangements.count(), 4);
+
+            let chars = ['a', 'B', 'b', 'A', 'c'];
+            assert_eq!(
+                PermS::multiset_perms_by_key(&chars, |c| c.to_ascii_lowercase()).count(),
+                30
+            );
+            assert_eq!(PermS::multiset_perms(&chars).count(), 120);
+            assert_eq!(PermS::multiset_perms(&[7; 6]).count(), 1);
+            assert_eq!(PermS::multiset_perms::<u8>(&[]).count(), 1);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{perm_type::PermD, size::Dynamic};
+
+    impl PermD {
+        /// Iterates over all dynamic permutations of given size in lexicographic order.
+        ///
+        /// Each item is a newly allocated copy. Step a single permutation with
+        /// [next_permutation](crate::Perm::next_permutation) to enumerate without allocation.
+        pub fn all(size: usize) -> AllPerms<Dynamic> {
+            AllPerms {
+                next: Some(Self::identity(size)),
+            }
+        }
+
+        /// Iterates over the dynamic k-permutations of given size in lexicographic order,
+        /// which is empty if `k` exceeds the size.
+        pub fn k_perms(size: usize, k: usize) -> KPerms<Dynamic> {
+            KPerms {
+                next: (k <= size).then(|| Self::identity(size)),
+                k,
+            }
+        }
+
+        /// Iterates over the distinct arrangements of a slice.
+        pub fn multiset_perms<T>(values: &[T]) -> MultisetPerms<Dynamic>
+        where
+            T: Ord,
+        {
+            Self::multiset_perms_by(values, T::cmp)
+        }
+
+        /// Iterates over the distinct arrangements of a slice, where elements are compared by a comparing function.
+        pub fn multiset_perms_by<T, F>(values: &[T], mut compare: F) -> MultisetPerms<Dynamic>
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            let sorted = Self::from_sort_by(values, &mut compare);
+            MultisetPerms::new(sorted, vec![0; values.len()], values, compare)
+        }
+
+        /// Iterates over the distinct arrangements of a slice, where elements with equal keys are indistinguishable.
+        pub fn multiset_perms_by_key<T, B, F>(values: &[T], mut f: F) -> MultisetPerms<Dynamic>
+        where
+            B: Ord,
+            F: FnMut(&T) -> B,
+        {
+            Self::multiset_perms_by(values, |lhs, rhs| f(lhs).cmp(&f(rhs)))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{apply::PermApply, perm_trait::Permutation};
+        use std::collections::HashSet;
+
+        #[test]
+        fn dynamic_all() {
+            assert_eq!(PermD::all(0).collect::<Vec<_>>(), vec![PermD::empty()]);
+            assert_eq!(PermD::all(6).count(), 720);
+
+            let forward: Vec<_> = PermD::all(4).collect();
+            let mut perm = PermD::identity(4);
+            assert!(!perm.prev_permutation());
+            let mut backward = vec![perm.clone()];
+            while perm.prev_permutation() {
+                backward.push(perm.clone());
+            }
+            backward.reverse();
+            assert_eq!(forward, backward);
+
+            let mut perm = PermD::identity(8);
+            let mut count = 1;
+            while perm.next_permutation() {
+                count += 1;
+            }
+            assert_eq!(count, 40320);
+            assert_eq!(perm, PermD::identity(8));
+        }
+
+        #[test]
+        fn dynamic_k_perms() {
+            (0..=6).for_each(|size| {
+                (0..=size + 1).for_each(|k| {
+                    let count = match k <= size {
+                        true => ((size - k + 1)..=size).product(),
+                        false => 0,
+                    };
+                    let prefixes: HashSet<_> = PermD::k_perms(size, k)
+                        .map(|perm| perm.indices()[..k].to_vec())
+                        .collect();
+                    assert_eq!(prefixes.len(), count);
+                });
+            });
+        }
+
+        #[test]
+        fn dynamic_multiset_perms() {
+            let words = vec!["x", "y", "x", "z", "y", "x"];
+            let arrangements: Vec<_> = PermD::multiset_perms(&words)
+                .map(|perm| {
+                    let mut words = words.clone();
+                    perm.apply(&mut words).unwrap();
+                    words
+                })
+                .collect();
+
+            // 6! / (3! 2! 1!)
+            assert_eq!(arrangements.len(), 60);
+            assert!(arrangements.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(arrangements[0], vec!["x", "x", "x", "y", "y", "z"]);
+
+            let perms: HashSet<_> =
+                PermD::multiset_perms_by(&words, |_, _| Ordering::Equal).collect();
+            assert_eq!(perms.len(), 1);
+        }
+    }
+}
+
+/// Steps the indices to the next permutation in the order, or wraps around and returns `false`.
+///
+/// Indices comparing equal are treated as identical, so that arrangements are not repeated.
+fn step_permutation<F>(indices: &mut [usize], order: Ordering, mut compare: F) -> bool
+where
+    F: FnMut(&usize, &usize) -> Ordering,
+{
+    let len = indices.len();
+
+    // find the rightmost position whose suffix is not at the end of the order
+    let pivot = match (1..len)
+        .rev()
+        .find(|&index| compare(&indices[index - 1], &indices[index]) == order)
+    {
+        Some(index) => index - 1,
+        None => {
+            indices.reverse();
+            return false;
+        }
+    };
+
+    // swap with the rightmost successor of the pivot, and restart the suffix
+    let successor = (pivot + 1..len)
+        .rev()
+        .find(|&index| compare(&indices[pivot], &indices[index]) == order)
+        .unwrap();
+    indices.swap(pivot, successor);
+    indices[(pivot + 1)..].reverse();
+    true
+}
@@ -0,0 +1,180 @@
+use crate::common::*;
+
+/// The error returned by fallible permutation operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PermError {
+    /// The input length does not match the permutation size.
+    LengthMismatch { expected: usize, actual: usize },
+    /// The index at `position` is not less than the permutation size `len`.
+    IndexOutOfRange {
+        position: usize,
+        index: usize,
+        len: usize,
+    },
+    /// The index appears at both `first` and `second` positions.
+    DuplicateIndex {
+        index: usize,
+        first: usize,
+        second: usize,
+    },
+    /// The index at `position` is not fixed and cannot be truncated when shrinking the permutation.
+    NonFixedTail { position: usize, index: usize },
+    /// The two indices of a transposition are identical.
+    IdenticalIndices { index: usize },
+    /// The product of an empty sequence of dynamic permutations, whose size is unknown.
+    EmptyProduct,
+    /// No permutation of size `len` satisfies the constraints.
+    NoPermutation { len: usize },
+    /// The rank of a permutation of size `len` overflows the integer type.
+    RankOverflow { len: usize },
+    /// The rank is not less than the number of permutations of size `len`.
+    RankOutOfRange { len: usize },
+    /// The digit of a Lehmer code or inversion vector at `position` is not less than `bound`.
+    InvalidDigit {
+        position: usize,
+        digit: usize,
+        bound: usize,
+    },
+    /// The memory for a permutation of size `len` cannot be allocated.
+    AllocationFailed { len: usize },
+}
+
+impl Display for PermError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::LengthMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "length mismatch: expect {}, but get {}",
+                    expected, actual
+                )
+            }
+            Self::IndexOutOfRange {
+                position,
+                index,
+                len,
+            } => write!(
+                f,
+                "index {} at position {} is out of range 0..{}",
+                index, position, len
+            ),
+            Self::DuplicateIndex {
+                index,
+                first,
+                second,
+            } => write!(
+                f,
+                "index {} appears at both positions {} and {}",
+                index, first, second
+            ),
+            Self::NonFixedTail { position, index } => write!(
+                f,
+                "cannot truncate position {} mapped to index {}",
+                position, index
+            ),
+            Self::IdenticalIndices { index } => {
+                write!(f, "cannot swap index {} with itself", index)
+            }
+            Self::EmptyProduct => write!(f, "cannot determine the size of an empty product"),
+            Self::NoPermutation { len } => write!(
+                f,
+                "no permutation of size {} satisfies the constraints",
+                len
+            ),
+            Self::RankOverflow { len } => {
+                write!(f, "the rank of permutation of size {} overflows", len)
+            }
+            Self::RankOutOfRange { len } => write!(
+                f,
+                "the rank exceeds the number of permutations of size {}",
+                len
+            ),
+            Self::InvalidDigit {
+                position,
+                digit,
+                bound,
+            } => write!(
+                f,
+                "digit {} at position {} is out of range 0..{}",
+                digit, position, bound
+            ),
+            Self::AllocationFailed { len } => {
+                write!(f, "cannot allocate a permutation of size {}", len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PermError {}
+
+impl PermError {
+    /// Shifts the positions in the error by an offset.
+    pub(crate) fn offset_positions(self, offset: usize) -> Self {
+        match self {
+            Self::IndexOutOfRange {
+                position,
+                index,
+                len,
+            } => Self::IndexOutOfRange {
+                position: position + offset,
+                index,
+                len,
+            },
+            Self::DuplicateIndex {
+                index,
+                first,
+                second,
+            } => Self::DuplicateIndex {
+                index,
+                first: first + offset,
+                second: second + offset,
+            },
+            error => error,
+        }
+    }
+}
+
+/// Checks that the indices are in range and not duplicated.
+pub(crate) fn check_indices<I>(indices: I, visited: &mut [bool]) -> Result<(), PermError>
+where
+    I: IntoIterator<Item = usize> + Clone,
+{
+    let len = visited.len();
+
+    indices
+        .clone()
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(position, index)| {
+            if index >= len {
+                return Err(PermError::IndexOutOfRange {
+                    position,
+                    index,
+                    len,
+                });
+            }
+            if visited[index] {
+                let first = indices
+                    .clone()
+                    .into_iter()
+                    .position(|other| other == index)
+                    .unwrap();
+                return Err(PermError::DuplicateIndex {
+                    index,
+                    first,
+                    second: position,
+                });
+            }
+            visited[index] = true;
+            Ok(())
+        })
+}
+
+/// Checks that the actual length equals the expected one.
+pub(crate) fn check_len(expected: usize, actual: usize) -> Result<(), PermError> {
+    if expected != actual {
+        return Err(PermError::LengthMismatch { expected, actual });
+    }
+    Ok(())
+}
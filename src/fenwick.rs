@@ -0,0 +1,72 @@
+/// A Fenwick tree counting a subset of `0..len` on a borrowed buffer.
+pub(crate) struct Fenwick<'a> {
+    tree: &'a mut [usize],
+}
+
+impl<'a> Fenwick<'a> {
+    /// Creates an empty subset.
+    pub fn empty(tree: &'a mut [usize]) -> Self {
+        tree.iter_mut().for_each(|count| *count = 0);
+        Self { tree }
+    }
+
+    /// Creates the subset containing all elements.
+    pub fn full(tree: &'a mut [usize]) -> Self {
+        // each node covers a range of length equal to its lowest bit
+        tree.iter_mut().enumerate().for_each(|(index, count)| {
+            *count = lowest_bit(index + 1);
+        });
+        Self { tree }
+    }
+
+    pub fn insert(&mut self, elem: usize) {
+        let mut node = elem + 1;
+        while node <= self.tree.len() {
+            self.tree[node - 1] += 1;
+            node += lowest_bit(node);
+        }
+    }
+
+    pub fn remove(&mut self, elem: usize) {
+        let mut node = elem + 1;
+        while node <= self.tree.len() {
+            self.tree[node - 1] -= 1;
+            node += lowest_bit(node);
+        }
+    }
+
+    /// Counts the elements less than `end`.
+    pub fn count_less(&self, end: usize) -> usize {
+        let mut node = end;
+        let mut count = 0;
+        while node > 0 {
+            count += self.tree[node - 1];
+            node -= lowest_bit(node);
+        }
+        count
+    }
+
+    /// Finds the `nth` smallest element counting from zero, which must exist.
+    pub fn select(&self, nth: usize) -> usize {
+        let len = self.tree.len();
+        let mut remaining = nth;
+        let mut pos = 0;
+        let mut step = match len {
+            0 => 0,
+            _ => 1 << (usize::BITS - 1 - len.leading_zeros()),
+        };
+
+        while step > 0 {
+            if pos + step <= len && self.tree[pos + step - 1] <= remaining {
+                pos += step;
+                remaining -= self.tree[pos - 1];
+            }
+            step >>= 1;
+        }
+        pos
+    }
+}
+
+fn lowest_bit(node: usize) -> usize {
+    node & node.wrapping_neg()
+}
@@ -0,0 +1,438 @@
+use crate::error::{check_indices, PermError};
+
+/// An operator that builds a permutation from a list of cycles.
+///
+/// The cycle `[a, b, c]` maps the index `a` to `b`, `b` to `c` and `c` to `a`,
+/// which is consistent with [cycles](crate::Permutation::cycles). The error positions
+/// are counted over the concatenated cycles.
+///
+/// Dynamic permutations accept the size along with cycles as `(size, cycles)`.
+/// Otherwise the size is inferred as the largest index in cycles plus one.
+pub trait PermFromCycles<T>
+where
+    Self: Sized,
+{
+    /// Builds a permutation from disjoint cycles.
+    fn from_cycles(cycles: T) -> Result<Self, PermError>;
+
+    /// Builds a permutation from a product of possibly overlapping cycles.
+    ///
+    /// The cycles are composed from left to right, that is, the result equals
+    /// to `c1 * c2 * ... * cn` where `ci` are the permutations of respective cycles.
+    fn from_cycle_product(cycles: T) -> Result<Self, PermError>;
+}
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<C, const SIZE: usize> PermFromCycles<&[C]> for PermS<SIZE>
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a static permutation from a slice of disjoint cycles.
+        fn from_cycles(cycles: &[C]) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            disjoint_cycles(cycles, &mut perm.indices, &mut [false; SIZE])?;
+            Ok(perm)
+        }
+
+        /// Builds a static permutation from a slice of overlapping cycles.
+        fn from_cycle_product(cycles: &[C]) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            cycle_product(cycles, &mut perm.indices, &mut [false; SIZE])?;
+            Ok(perm)
+        }
+    }
+
+    impl<C, const SIZE: usize, const LEN: usize> PermFromCycles<&[C; LEN]> for PermS<SIZE>
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a static permutation from an array of disjoint cycles.
+        fn from_cycles(cycles: &[C; LEN]) -> Result<Self, PermError> {
+            Self::from_cycles(cycles.as_ref())
+        }
+
+        /// Builds a static permutation from an array of overlapping cycles.
+        fn from_cycle_product(cycles: &[C; LEN]) -> Result<Self, PermError> {
+            Self::from_cycle_product(cycles.as_ref())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{from_indices::PermFromIndices, perm_trait::Permutation};
+
+        #[test]
+        fn static_from_cycles() {
+            let perm = PermS::<6>::from_cycles(&[&[0, 2, 4][..], &[1, 3]]).unwrap();
+            assert_eq!(perm, PermS::from_indices([2, 3, 4, 1, 0, 5]).unwrap());
+
+            let empty: &[&[usize]] = &[];
+            assert_eq!(
+                PermS::<6>::from_cycles(empty).unwrap(),
+                PermS::<6>::identity()
+            );
+            assert_eq!(
+                PermS::<6>::from_cycles(&[[5, 3]]).unwrap(),
+                PermS::<6>::swap(3, 5).unwrap()
+            );
+
+            // overlapping, out of range and repeated elements
+            assert_eq!(
+                PermS::<6>::from_cycles(&[&[0, 2, 4][..], &[1, 2]]),
+                Err(PermError::DuplicateIndex {
+                    index: 2,
+                    first: 1,
+                    second: 4
+                })
+            );
+            assert_eq!(
+                PermS::<6>::from_cycles(&[[0, 6]]),
+                Err(PermError::IndexOutOfRange {
+                    position: 1,
+                    index: 6,
+                    len: 6
+                })
+            );
+            assert!(PermS::<6>::from_cycles(&[[1, 1]]).is_err());
+        }
+
+        #[test]
+        fn static_from_cycle_product() {
+            let product =
+                PermS::<5>::from_cycle_product(&[&[0, 1, 2][..], &[2, 3], &[0, 4]]).unwrap();
+            let expect = &(&PermS::<5>::from_cycles(&[[0, 1, 2]]).unwrap()
+                * &PermS::<5>::swap(2, 3).unwrap())
+                * &PermS::<5>::swap(0, 4).unwrap();
+            assert_eq!(product, expect);
+
+            assert_eq!(
+                PermS::<3>::from_cycle_product(&[[0, 1], [0, 1]]).unwrap(),
+                PermS::<3>::identity()
+            );
+            assert!(PermS::<3>::from_cycle_product(&[[0, 3]]).is_err());
+            assert_eq!(
+                PermS::<3>::from_cycle_product(&[[1, 2, 0], [0, 1, 0]]),
+                Err(PermError::DuplicateIndex {
+                    index: 0,
+                    first: 3,
+                    second: 5
+                })
+            );
+
+            let perm = PermS::from_indices([3, 0, 4, 1, 2]).unwrap();
+            let mut cycles = [[0; 5]; 5];
+            let mut lens = [0; 5];
+            perm.cycles().enumerate().for_each(|(nth, cycle)| {
+                lens[nth] = cycle.len();
+                cycle
+                    .zip(cycles[nth].iter_mut())
+                    .for_each(|(index, slot)| *slot = index);
+            });
+            let cycles = [&cycles[0][..lens[0]], &cycles[1][..lens[1]]];
+            assert_eq!(PermS::<5>::from_cycles(&cycles).unwrap(), perm);
+            assert_eq!(PermS::<5>::from_cycle_product(&cycles).unwrap(), perm);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{
+        common::*,
+        perm_type::{PermD, PermS},
+    };
+
+    impl<C> PermFromCycles<&[C]> for PermD
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a dynamic permutation from a slice of disjoint cycles.
+        ///
+        /// The size is the largest index in cycles plus one.
+        fn from_cycles(cycles: &[C]) -> Result<Self, PermError> {
+            let mut perm = cycles_identity(cycles)?;
+            let len = perm.indices.len();
+            disjoint_cycles(cycles, &mut perm.indices, &mut vec![false; len])?;
+            Ok(perm)
+        }
+
+        /// Builds a dynamic permutation from a slice of overlapping cycles.
+        ///
+        /// The size is the largest index in cycles plus one.
+        fn from_cycle_product(cycles: &[C]) -> Result<Self, PermError> {
+            let mut perm = cycles_identity(cycles)?;
+            let len = perm.indices.len();
+            cycle_product(cycles, &mut perm.indices, &mut vec![false; len])?;
+            Ok(perm)
+        }
+    }
+
+    impl<C> PermFromCycles<(usize, &[C])> for PermD
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a dynamic permutation of given size from a slice of disjoint cycles.
+        fn from_cycles((size, cycles): (usize, &[C])) -> Result<Self, PermError> {
+            let mut perm = Self::identity(size);
+            disjoint_cycles(cycles, &mut perm.indices, &mut vec![false; size])?;
+            Ok(perm)
+        }
+
+        /// Builds a dynamic permutation of given size from a slice of overlapping cycles.
+        fn from_cycle_product((size, cycles): (usize, &[C])) -> Result<Self, PermError> {
+            let mut perm = Self::identity(size);
+            cycle_product(cycles, &mut perm.indices, &mut vec![false; size])?;
+            Ok(perm)
+        }
+    }
+
+    impl<C, const LEN: usize> PermFromCycles<(usize, &[C; LEN])> for PermD
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a dynamic permutation of given size from an array of disjoint cycles.
+        fn from_cycles((size, cycles): (usize, &[C; LEN])) -> Result<Self, PermError> {
+            Self::from_cycles((size, cycles.as_ref()))
+        }
+
+        /// Builds a dynamic permutation of given size from an array of overlapping cycles.
+        fn from_cycle_product((size, cycles): (usize, &[C; LEN])) -> Result<Self, PermError> {
+            Self::from_cycle_product((size, cycles.as_ref()))
+        }
+    }
+
+    impl<C> PermFromCycles<(usize, &Vec<C>)> for PermD
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a dynamic permutation of given size from a vector of disjoint cycles.
+        fn from_cycles((size, cycles): (usize, &Vec<C>)) -> Result<Self, PermError> {
+            Self::from_cycles((size, cycles.as_slice()))
+        }
+
+        /// Builds a dynamic permutation of given size from a vector of overlapping cycles.
+        fn from_cycle_product((size, cycles): (usize, &Vec<C>)) -> Result<Self, PermError> {
+            Self::from_cycle_product((size, cycles.as_slice()))
+        }
+    }
+
+    impl<C, const LEN: usize> PermFromCycles<&[C; LEN]> for PermD
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a dynamic permutation from an array of disjoint cycles.
+        fn from_cycles(cycles: &[C; LEN]) -> Result<Self, PermError> {
+            Self::from_cycles(cycles.as_ref())
+        }
+
+        /// Builds a dynamic permutation from an array of overlapping cycles.
+        fn from_cycle_product(cycles: &[C; LEN]) -> Result<Self, PermError> {
+            Self::from_cycle_product(cycles.as_ref())
+        }
+    }
+
+    impl<C> PermFromCycles<&Vec<C>> for PermD
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a dynamic permutation from a vector of disjoint cycles.
+        fn from_cycles(cycles: &Vec<C>) -> Result<Self, PermError> {
+            Self::from_cycles(cycles.as_slice())
+        }
+
+        /// Builds a dynamic permutation from a vector of overlapping cycles.
+        fn from_cycle_product(cycles: &Vec<C>) -> Result<Self, PermError> {
+            Self::from_cycle_product(cycles.as_slice())
+        }
+    }
+
+    impl<C, const SIZE: usize> PermFromCycles<&Vec<C>> for PermS<SIZE>
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Builds a static permutation from a vector of disjoint cycles.
+        fn from_cycles(cycles: &Vec<C>) -> Result<Self, PermError> {
+            Self::from_cycles(cycles.as_slice())
+        }
+
+        /// Builds a static permutation from a vector of overlapping cycles.
+        fn from_cycle_product(cycles: &Vec<C>) -> Result<Self, PermError> {
+            Self::from_cycle_product(cycles.as_slice())
+        }
+    }
+
+    /// Builds the identity sized by the largest index in cycles plus one.
+    ///
+    /// It fails if the size overflows or cannot be allocated.
+    fn cycles_identity<C>(cycles: &[C]) -> Result<PermD, PermError>
+    where
+        C: AsRef<[usize]>,
+    {
+        let max = cycles
+            .iter()
+            .flat_map(|cycle| cycle.as_ref().iter().copied())
+            .enumerate()
+            .max_by_key(|&(_, index)| index);
+
+        let (position, index) = match max {
+            Some(max) => max,
+            None => return Ok(PermD::empty()),
+        };
+        let size = index.checked_add(1).ok_or(PermError::IndexOutOfRange {
+            position,
+            index,
+            len: usize::MAX,
+        })?;
+        PermD::try_identity(size).ok_or(PermError::AllocationFailed { len: size })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::perm_trait::Permutation;
+        use rand::prelude::*;
+
+        #[test]
+        fn dynamic_from_cycles() {
+            let perm = PermD::from_cycles(&[vec![0, 2, 4], vec![1, 3]]).unwrap();
+            assert_eq!(perm.indices(), &[2, 3, 4, 1, 0]);
+
+            let empty: Vec<Vec<usize>> = vec![];
+            assert_eq!(PermD::from_cycles(&empty).unwrap(), PermD::empty());
+            assert!(PermD::from_cycles(&[vec![0, 2, 4], vec![4, 3]]).is_err());
+
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(1024);
+            perm.indices.shuffle(&mut rng);
+
+            let cycles: Vec<Vec<_>> = perm.cycles().map(|cycle| cycle.collect()).collect();
+            assert_eq!(PermD::from_cycles((1024, &cycles)).unwrap(), perm);
+            assert_eq!(PermD::from_cycle_product((1024, &cycles)).unwrap(), perm);
+        }
+
+        #[test]
+        fn dynamic_from_cycles_with_size() {
+            assert_eq!(
+                PermD::from_cycles((5, &[[0, 1]])).unwrap(),
+                PermD::swap(5, 0, 1).unwrap()
+            );
+            assert_eq!(
+                PermD::from_cycle_product((5, &vec![vec![0, 1], vec![1, 2]])).unwrap(),
+                PermD::from_cycles((5, &[[0, 1, 2]])).unwrap()
+            );
+
+            let empty: &[&[usize]] = &[];
+            assert_eq!(PermD::from_cycles((3, empty)).unwrap(), PermD::identity(3));
+            assert_eq!(
+                PermD::from_cycles((5, &[[0, 5]])),
+                Err(PermError::IndexOutOfRange {
+                    position: 1,
+                    index: 5,
+                    len: 5
+                })
+            );
+            assert_eq!(
+                PermD::from_cycle_product((3, &[vec![1, 0], vec![usize::MAX]])),
+                Err(PermError::IndexOutOfRange {
+                    position: 2,
+                    index: usize::MAX,
+                    len: 3
+                })
+            );
+        }
+
+        #[test]
+        fn dynamic_from_cycle_product() {
+            let product = PermD::from_cycle_product(&[vec![0, 1, 2], vec![2, 3]]).unwrap();
+            assert_eq!(product, PermD::from_cycles(&[[0, 1, 2, 3]]).unwrap());
+            assert!(PermD::from_cycle_product(&[vec![2, 2]]).is_err());
+        }
+
+        #[test]
+        fn dynamic_from_cycles_huge_index() {
+            assert_eq!(
+                PermD::from_cycles(&[[0, usize::MAX]]),
+                Err(PermError::IndexOutOfRange {
+                    position: 1,
+                    index: usize::MAX,
+                    len: usize::MAX
+                })
+            );
+            assert_eq!(
+                PermD::from_cycle_product(&[vec![1, 0], vec![usize::MAX / 2]]),
+                Err(PermError::AllocationFailed {
+                    len: usize::MAX / 2 + 1
+                })
+            );
+        }
+    }
+}
+
+/// Writes disjoint cycles on identity indices.
+fn disjoint_cycles<C>(
+    cycles: &[C],
+    indices: &mut [usize],
+    visited: &mut [bool],
+) -> Result<(), PermError>
+where
+    C: AsRef<[usize]>,
+{
+    check_indices(
+        cycles
+            .iter()
+            .flat_map(|cycle| cycle.as_ref().iter().copied()),
+        visited,
+    )?;
+
+    cycles.iter().for_each(|cycle| {
+        let cycle = cycle.as_ref();
+        cycle
+            .iter()
+            .zip(cycle.iter().skip(1).chain(cycle.first()))
+            .for_each(|(&src, &dst)| {
+                indices[src] = dst;
+            });
+    });
+    Ok(())
+}
+
+/// Multiplies cycles to the right of indices in order.
+fn cycle_product<C>(
+    cycles: &[C],
+    indices: &mut [usize],
+    visited: &mut [bool],
+) -> Result<(), PermError>
+where
+    C: AsRef<[usize]>,
+{
+    let len = indices.len();
+    let mut offset = 0;
+
+    cycles.iter().try_for_each(|cycle| {
+        let cycle = cycle.as_ref();
+
+        let result = check_indices(cycle.iter().copied(), visited);
+        cycle
+            .iter()
+            .filter(|&&index| index < len)
+            .for_each(|&index| visited[index] = false);
+        result.map_err(|error| error.offset_positions(offset))?;
+        offset += cycle.len();
+
+        if let Some((&first, rest)) = cycle.split_first() {
+            let first_index = indices[first];
+            let mut prev = first;
+            rest.iter().for_each(|&curr| {
+                indices[prev] = indices[curr];
+                prev = curr;
+            });
+            indices[prev] = first_index;
+        }
+        Ok(())
+    })
+}
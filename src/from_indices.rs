@@ -1,11 +1,14 @@
-use crate::common::*;
+use crate::{
+    common::*,
+    error::{check_indices, check_len, PermError},
+};
 
 /// An operator that builds a permutation from a list indexes.
 pub trait PermFromIndices<T>
 where
     Self: Sized,
 {
-    fn from_indices(indices: T) -> Option<Self>;
+    fn from_indices(indices: T) -> Result<Self, PermError>;
 }
 
 mod without_std {
@@ -14,49 +17,41 @@ mod without_std {
 
     impl<const SIZE: usize> PermFromIndices<[usize; SIZE]> for PermS<SIZE> {
         /// Builds a static permutation from an owned array of indices.
-        fn from_indices(indices: [usize; SIZE]) -> Option<Self> {
-            if !check_indices(indices.as_ref(), &mut [false; SIZE]) {
-                return None;
-            }
-            Some(Self { indices })
+        fn from_indices(indices: [usize; SIZE]) -> Result<Self, PermError> {
+            check_indices(indices.iter().copied(), &mut [false; SIZE])?;
+            Ok(Self { indices })
         }
     }
 
     impl<const SIZE: usize> PermFromIndices<&[usize; SIZE]> for PermS<SIZE> {
         /// Builds a static permutation from a borrowed array of indices.
-        fn from_indices(indices: &[usize; SIZE]) -> Option<Self> {
+        fn from_indices(indices: &[usize; SIZE]) -> Result<Self, PermError> {
             Self::from_indices(indices.as_ref())
         }
     }
 
     impl<const SIZE: usize> PermFromIndices<&[usize]> for PermS<SIZE> {
         /// Builds a static permutation from a slice of indices.
-        fn from_indices(indices: &[usize]) -> Option<Self> {
-            if indices.len() != SIZE {
-                return None;
-            }
-            if !check_indices(indices, &mut [false; SIZE]) {
-                return None;
-            }
-            Some(Self {
+        fn from_indices(indices: &[usize]) -> Result<Self, PermError> {
+            check_len(SIZE, indices.len())?;
+            check_indices(indices.iter().copied(), &mut [false; SIZE])?;
+            Ok(Self {
                 indices: indices.try_into().unwrap(),
             })
         }
     }
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
     use crate::perm_type::{PermD, PermS};
 
     impl PermFromIndices<Cow<'_, [usize]>> for PermD {
         /// Builds a dynamic permutation from a copy-on-write slice of indices.
-        fn from_indices(indices: Cow<'_, [usize]>) -> Option<Self> {
-            if !check_indices(indices.as_ref(), &mut vec![false; indices.len()]) {
-                return None;
-            }
-            Some(Self {
+        fn from_indices(indices: Cow<'_, [usize]>) -> Result<Self, PermError> {
+            check_indices(indices.iter().copied(), &mut vec![false; indices.len()])?;
+            Ok(Self {
                 indices: indices.into_owned(),
             })
         }
@@ -64,31 +59,29 @@ mod with_std {
 
     impl PermFromIndices<Vec<usize>> for PermD {
         /// Builds a dynamic permutation from a vector of indices.
-        fn from_indices(indices: Vec<usize>) -> Option<Self> {
-            if !check_indices(indices.as_slice(), &mut vec![false; indices.len()]) {
-                return None;
-            }
-            Some(Self { indices })
+        fn from_indices(indices: Vec<usize>) -> Result<Self, PermError> {
+            check_indices(indices.iter().copied(), &mut vec![false; indices.len()])?;
+            Ok(Self { indices })
         }
     }
 
     impl PermFromIndices<&'_ [usize]> for PermD {
         /// Builds a dynamic permutation from a slice of indices.
-        fn from_indices(indices: &[usize]) -> Option<Self> {
+        fn from_indices(indices: &[usize]) -> Result<Self, PermError> {
             Self::from_indices(Cow::<'_, [usize]>::from(indices))
         }
     }
 
     impl<const SIZE: usize> PermFromIndices<[usize; SIZE]> for PermD {
         /// Builds a dynamic permutation from an array of indices.
-        fn from_indices(indices: [usize; SIZE]) -> Option<Self> {
+        fn from_indices(indices: [usize; SIZE]) -> Result<Self, PermError> {
             Self::from_indices(indices.as_ref())
         }
     }
 
     impl<const SIZE: usize> PermFromIndices<Vec<usize>> for PermS<SIZE> {
         /// Builds a static permutation from a vector indices.
-        fn from_indices(indices: Vec<usize>) -> Option<Self> {
+        fn from_indices(indices: Vec<usize>) -> Result<Self, PermError> {
             let indices: &[usize] = indices.as_ref();
             Self::from_indices(indices)
         }
@@ -96,20 +89,8 @@ mod with_std {
 
     impl<const SIZE: usize> PermFromIndices<Cow<'_, [usize]>> for PermS<SIZE> {
         /// Builds a static permutation from a copy-on-write slice of indices.
-        fn from_indices(indices: Cow<'_, [usize]>) -> Option<Self> {
+        fn from_indices(indices: Cow<'_, [usize]>) -> Result<Self, PermError> {
             Self::from_indices(indices.as_ref())
         }
     }
 }
-
-fn check_indices(indices: &[usize], visited: &mut [bool]) -> bool {
-    let len = indices.len();
-    indices.iter().all(|&index| {
-        if index >= len || visited[index] {
-            false
-        } else {
-            visited[index] = true;
-            true
-        }
-    })
-}
@@ -1,4 +1,7 @@
-use crate::common::*;
+use crate::{
+    common::*,
+    error::{check_len, PermError},
+};
 
 use algorithm::*;
 
@@ -114,30 +117,26 @@ mod without_std {
     }
 
     impl<T, const SIZE: usize> PermFromSorting<&[T], T> for PermS<SIZE> {
-        type Output = Option<Self>;
+        type Output = Result<Self, PermError>;
 
         fn from_sort(vec: &[T]) -> Self::Output
         where
             T: Ord,
         {
-            if vec.len() != SIZE {
-                return None;
-            }
+            check_len(SIZE, vec.len())?;
             let mut perm = Self::identity();
             sort(&mut perm.indices, vec);
-            Some(perm)
+            Ok(perm)
         }
 
         fn from_sort_by<F>(vec: &[T], compare: F) -> Self::Output
         where
             F: FnMut(&T, &T) -> Ordering,
         {
-            if vec.len() != SIZE {
-                return None;
-            }
+            check_len(SIZE, vec.len())?;
             let mut perm = Self::identity();
             sort_by(&mut perm.indices, vec, compare);
-            Some(perm)
+            Ok(perm)
         }
 
         fn from_sort_by_key<B, F>(vec: &[T], f: F) -> Self::Output
@@ -145,12 +144,10 @@ mod without_std {
             B: Ord,
             F: FnMut(&T) -> B,
         {
-            if vec.len() != SIZE {
-                return None;
-            }
+            check_len(SIZE, vec.len())?;
             let mut perm = Self::identity();
             sort_by_key(&mut perm.indices, vec, f);
-            Some(perm)
+            Ok(perm)
         }
 
         fn from_sort_by_cached_key<B, F>(vec: &[T], f: F) -> Self::Output
@@ -158,12 +155,10 @@ mod without_std {
             B: Ord,
             F: FnMut(&T) -> B,
         {
-            if vec.len() != SIZE {
-                return None;
-            }
+            check_len(SIZE, vec.len())?;
             let mut perm = Self::identity();
             sort_by_cached_key(&mut perm.indices, vec, f);
-            Some(perm)
+            Ok(perm)
         }
     }
 
@@ -174,6 +169,7 @@ mod without_std {
         use rand::prelude::*;
 
         #[test]
+        #[allow(clippy::clone_on_copy, clippy::unnecessary_sort_by)]
         fn static_perm_from_array() {
             const SIZE: usize = 1024;
             let mut rng = rand::thread_rng();
@@ -241,8 +237,8 @@ mod without_std {
     }
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
     use crate::perm_type::{PermD, PermS};
 
@@ -391,7 +387,7 @@ mod with_std {
     }
 
     impl<T, const SIZE: usize> PermFromSorting<Vec<T>, T> for PermS<SIZE> {
-        type Output = Option<Self>;
+        type Output = Result<Self, PermError>;
 
         fn from_sort(vec: Vec<T>) -> Self::Output
         where
@@ -431,6 +427,7 @@ mod with_std {
         use rand::prelude::*;
 
         #[test]
+        #[allow(clippy::unnecessary_sort_by)]
         fn static_perm_from_vec() {
             const SIZE: usize = 1024;
             let mut rng = rand::thread_rng();
@@ -502,6 +499,7 @@ mod with_std {
         }
 
         #[test]
+        #[allow(clippy::unnecessary_sort_by)]
         fn dynamic_perm_from_vec() {
             const SIZE: usize = 1024;
             let mut rng = rand::thread_rng();
@@ -569,7 +567,7 @@ mod with_std {
     }
 }
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(feature = "alloc"))]
 mod algorithm {
     use super::*;
 
@@ -702,7 +700,7 @@ mod algorithm {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 mod algorithm {
     use super::*;
 
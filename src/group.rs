@@ -0,0 +1,964 @@
+#![cfg(feature = "alloc")]
+
+use crate::{
+    common::*,
+    error::{check_len, PermError},
+    from_indices::PermFromIndices,
+    perm_trait::Permutation,
+    perm_type::{PermD, PermS},
+};
+use alloc::collections::BTreeSet;
+
+/// A permutation group generated by a set of permutations.
+///
+/// A base and strong generating set is computed by the Schreier–Sims algorithm on creation.
+/// The product `a * b` of group elements maps the point `i` to `a(b(i))`, where a
+/// permutation maps `i` to `indices()[i]`.
+#[derive(Debug, Clone)]
+pub struct PermGroup<P> {
+    size: usize,
+    identity: P,
+    generators: Vec<P>,
+    strong_generators: Vec<P>,
+    levels: Vec<Level<P>>,
+}
+
+/// The stabilizer chain level of a base point.
+#[derive(Debug, Clone)]
+struct Level<P> {
+    point: usize,
+    // the strong generators fixing the previous base points
+    generators: Vec<usize>,
+    // the element mapping the base point to each orbit point
+    transversal: Vec<Option<P>>,
+    orbit: Vec<usize>,
+}
+
+impl<const SIZE: usize> PermGroup<PermS<SIZE>> {
+    /// Builds the group generated by static permutations.
+    pub fn new_static(generators: &[PermS<SIZE>]) -> Self {
+        Self::build(SIZE, PermS::identity(), generators, &[])
+    }
+
+    /// Builds the symmetric group of all static permutations.
+    pub fn symmetric_static() -> Self {
+        Self::from_named(symmetric_generators(SIZE))
+    }
+
+    /// Builds the alternating group of even static permutations.
+    pub fn alternating_static() -> Self {
+        Self::from_named(alternating_generators(SIZE))
+    }
+
+    /// Builds the cyclic group generated by the rotation of points.
+    pub fn cyclic_static() -> Self {
+        Self::from_named(cyclic_generators(SIZE))
+    }
+
+    /// Builds the dihedral group of symmetries of a regular polygon with `SIZE` vertices.
+    pub fn dihedral_static() -> Self {
+        Self::from_named(dihedral_generators(SIZE))
+    }
+
+    fn from_named(generators: Vec<Vec<usize>>) -> Self {
+        let generators: Vec<_> = generators
+            .iter()
+            .map(|indices| PermS::from_indices(indices.as_slice()).unwrap())
+            .collect();
+        Self::new_static(&generators)
+    }
+}
+
+impl PermGroup<PermD> {
+    /// Builds the group of permutations on `0..size` generated by dynamic permutations.
+    ///
+    /// It fails if any generator has a size other than `size`.
+    pub fn new(size: usize, generators: &[PermD]) -> Result<Self, PermError> {
+        generators
+            .iter()
+            .try_for_each(|generator| check_len(size, generator.len()))?;
+        Ok(Self::build(size, PermD::identity(size), generators, &[]))
+    }
+
+    /// Builds the symmetric group of all permutations on `0..size`.
+    ///
+    /// It is generated by the rotation of all points and the swap of the first two points.
+    pub fn symmetric(size: usize) -> Self {
+        Self::from_named(size, symmetric_generators(size))
+    }
+
+    /// Builds the alternating group of even permutations on `0..size`.
+    ///
+    /// It is generated by the 3-cycles `(0 1 k)`.
+    pub fn alternating(size: usize) -> Self {
+        Self::from_named(size, alternating_generators(size))
+    }
+
+    /// Builds the cyclic group generated by the rotation of points `0..size`.
+    pub fn cyclic(size: usize) -> Self {
+        Self::from_named(size, cyclic_generators(size))
+    }
+
+    /// Builds the dihedral group of symmetries of a regular polygon with vertices `0..size`,
+    /// which has order `2 * size` for `size` at least 3.
+    ///
+    /// It is generated by the rotation and the reflection fixing the vertex 0.
+    pub fn dihedral(size: usize) -> Self {
+        Self::from_named(size, dihedral_generators(size))
+    }
+
+    fn from_named(size: usize, generators: Vec<Vec<usize>>) -> Self {
+        let generators: Vec<_> = generators
+            .into_iter()
+            .map(|indices| PermD::from_indices(indices).unwrap())
+            .collect();
+        Self::new(size, &generators).unwrap()
+    }
+}
+
+impl<P> PermGroup<P>
+where
+    P: Permutation + Clone + PartialEq,
+    for<'a> &'a P: Mul<&'a P, Output = P>,
+{
+    /// Gets the number of points the group acts on.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Gets the identity element.
+    pub fn identity(&self) -> &P {
+        &self.identity
+    }
+
+    /// Gets the generators given on creation.
+    pub fn generators(&self) -> &[P] {
+        &self.generators
+    }
+
+    /// Gets the strong generating set relative to the base.
+    pub fn strong_generators(&self) -> &[P] {
+        &self.strong_generators
+    }
+
+    /// Gets the base, the points whose images determine a group element.
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|level| level.point).collect()
+    }
+
+    /// Gets the group order, which is `None` if it overflows `u128`.
+    pub fn order(&self) -> Option<u128> {
+        self.levels.iter().try_fold(1u128, |order, level| {
+            order.checked_mul(level.orbit.len() as u128)
+        })
+    }
+
+    /// Checks if a permutation is a member of the group.
+    pub fn contains(&self, perm: &P) -> bool {
+        self.factorize(perm).is_some()
+    }
+
+    /// Factorizes a member into transversal elements by sifting, or returns `None` for a non-member.
+    ///
+    /// The product of the returned elements from left to right equals the permutation.
+    pub fn factorize(&self, perm: &P) -> Option<Vec<P>> {
+        if perm.len() != self.size {
+            return None;
+        }
+
+        let mut factors = vec![];
+        let (residue, depth) = self.sift_with(perm.clone(), 0, |factor| {
+            factors.push(factor.clone());
+        });
+        (depth == self.levels.len() && residue == self.identity).then_some(factors)
+    }
+
+    /// Gets the orbit of a point in breadth-first order, which panics if the point is out of range.
+    pub fn orbit(&self, point: usize) -> Vec<usize> {
+        self.schreier_vector(point).orbit
+    }
+
+    /// Gets the orbit of a set of points, where each set is sorted and deduplicated.
+    ///
+    /// It panics if any point is out of range.
+    pub fn set_orbit(&self, set: &[usize]) -> Vec<Vec<usize>> {
+        let set = normalize_set(set, self.size);
+        let mut visited = BTreeSet::new();
+        visited.insert(set.clone());
+        let mut orbit = vec![set];
+        let mut nth = 0;
+
+        while nth < orbit.len() {
+            self.generators.iter().for_each(|generator| {
+                let mut image: Vec<_> = orbit[nth]
+                    .iter()
+                    .map(|&point| image(generator, point))
+                    .collect();
+                image.sort_unstable();
+
+                if visited.insert(image.clone()) {
+                    orbit.push(image);
+                }
+            });
+            nth += 1;
+        }
+        orbit
+    }
+
+    /// Partitions the points into orbits, each sorted and ordered by its smallest point.
+    pub fn orbits(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.size];
+
+        (0..self.size)
+            .filter_map(|point| {
+                if visited[point] {
+                    return None;
+                }
+                let mut orbit = self.orbit(point);
+                orbit.iter().for_each(|&point| visited[point] = true);
+                orbit.sort_unstable();
+                Some(orbit)
+            })
+            .collect()
+    }
+
+    /// Builds the Schreier vector of the orbit of a point, which panics if the point is out of range.
+    pub fn schreier_vector(&self, point: usize) -> SchreierVector<'_, P> {
+        let mut labels = vec![None; self.size];
+        let mut orbit = vec![point];
+        let mut nth = 0;
+
+        while nth < orbit.len() {
+            let parent = orbit[nth];
+            nth += 1;
+
+            self.generators
+                .iter()
+                .enumerate()
+                .for_each(|(index, generator)| {
+                    let image = image(generator, parent);
+                    if image != point && labels[image].is_none() {
+                        labels[image] = Some(index);
+                        orbit.push(image);
+                    }
+                });
+        }
+
+        SchreierVector {
+            group: self,
+            root: point,
+            labels,
+            orbit,
+        }
+    }
+
+    /// Builds the stabilizer subgroup of a point, which panics if the point is out of range.
+    pub fn stabilizer(&self, point: usize) -> Self {
+        let schreier_vector = self.schreier_vector(point);
+        let transversal = schreier_vector.transversal();
+
+        // the Schreier generators generate the stabilizer
+        let generators: Vec<_> = schreier_vector
+            .orbit
+            .iter()
+            .flat_map(|&orbit_point| {
+                let coset = transversal[orbit_point].as_ref().unwrap();
+                let transversal = &transversal;
+
+                self.generators.iter().filter_map(move |generator| {
+                    let image = image(generator, orbit_point);
+                    let inverse = transversal[image].as_ref().unwrap().inverse();
+                    let schreier = &(&inverse * generator) * coset;
+                    (schreier != self.identity).then_some(schreier)
+                })
+            })
+            .collect();
+
+        Self::build(self.size, self.identity.clone(), &generators, &[point])
+    }
+
+    /// Builds the setwise stabilizer subgroup of a set of points by backtracking over base images.
+    ///
+    /// It panics if any point is out of range.
+    pub fn set_stabilizer(&self, set: &[usize]) -> Self {
+        let set = normalize_set(set, self.size);
+        let mut in_set = vec![false; self.size];
+        set.iter().for_each(|&point| in_set[point] = true);
+
+        // the elements are products of transversal elements, where the
+        // prefix on the set points determines the image of the set
+        let chain = Self::build(self.size, self.identity.clone(), &self.generators, &set);
+        let pointwise: Vec<_> = chain
+            .strong_generators
+            .iter()
+            .filter(|generator| set.iter().all(|&point| image(*generator, point) == point))
+            .cloned()
+            .collect();
+        let mut stabilizer = Self::build(self.size, self.identity.clone(), &pointwise, &set);
+        chain.search_set_images(0, &chain.identity, &in_set, &mut stabilizer);
+        stabilizer
+    }
+
+    /// Adds the prefixes of elements on the leading levels that map the set to itself to the stabilizer.
+    ///
+    /// The subtree of a prefix is skipped if the stabilizer already has an element with the
+    /// same images of the base points, since the identity subtrees visited first have added
+    /// all elements fixing these base points.
+    fn search_set_images(&self, depth: usize, prefix: &P, in_set: &[bool], stabilizer: &mut Self) {
+        let level = match self.levels.get(depth) {
+            Some(level) if in_set[level.point] => level,
+            _ => {
+                stabilizer.add_generator(prefix.clone());
+                return;
+            }
+        };
+
+        level.orbit.iter().for_each(|&point| {
+            let element = prefix * level.transversal[point].as_ref().unwrap();
+            if !in_set[image(&element, level.point)] {
+                return;
+            }
+            if element != self.identity
+                && stabilizer.sift_with(element.clone(), 0, |_| {}).1 > depth
+            {
+                return;
+            }
+            self.search_set_images(depth + 1, &element, in_set, stabilizer);
+        });
+    }
+
+    /// Builds the direct product acting on the points of both groups, where the points of `other` are shifted after the points of `self`.
+    pub fn direct_product<Q>(&self, other: &PermGroup<Q>) -> PermGroup<PermD>
+    where
+        Q: Permutation + Clone + PartialEq,
+        for<'a> &'a Q: Mul<&'a Q, Output = Q>,
+    {
+        let size = self.size + other.size;
+        let lower = self.generators.iter().map(|generator| {
+            generator
+                .indices()
+                .iter()
+                .copied()
+                .chain(self.size..size)
+                .collect::<Vec<_>>()
+        });
+        let upper = other.generators.iter().map(|generator| {
+            (0..self.size)
+                .chain(generator.indices().iter().map(|&index| index + self.size))
+                .collect::<Vec<_>>()
+        });
+        PermGroup::<PermD>::from_named(size, lower.chain(upper).collect())
+    }
+
+    /// Iterates over all group elements, each exactly once.
+    pub fn elements(&self) -> Elements<'_, P> {
+        Elements {
+            group: self,
+            choices: Some(vec![0; self.levels.len()]),
+        }
+    }
+
+    /// Builds an element from transversal indices, one per level, which are taken modulo the orbit lengths.
+    pub(crate) fn element_at<I>(&self, choices: I) -> P
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.levels
+            .iter()
+            .zip(choices)
+            .fold(self.identity.clone(), |element, (level, choice)| {
+                let point = level.orbit[choice % level.orbit.len()];
+                &element * level.transversal[point].as_ref().unwrap()
+            })
+    }
+
+    /// Gets the orbit lengths of base points in order.
+    pub(crate) fn basic_orbit_lens(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator + '_ {
+        self.levels.iter().map(|level| level.orbit.len())
+    }
+
+    /// Builds the group whose base starts with the distinct points in `base`.
+    fn build(size: usize, identity: P, generators: &[P], base: &[usize]) -> Self {
+        let mut group = Self {
+            size,
+            identity,
+            generators: generators.to_vec(),
+            strong_generators: vec![],
+            levels: vec![],
+        };
+        base.iter().for_each(|&point| group.push_level(point));
+
+        generators.iter().for_each(|generator| {
+            // skip the generators already in the partial group
+            let (residue, depth) = group.sift_with(generator.clone(), 0, |_| {});
+            if residue != group.identity {
+                group.add_strong_generator(residue, 0, depth);
+            }
+        });
+
+        group.schreier_sims();
+        group
+    }
+
+    /// Adds a generator unless it is already in the group, and completes the strong generating set.
+    fn add_generator(&mut self, generator: P) {
+        let (residue, depth) = self.sift_with(generator.clone(), 0, |_| {});
+        if residue != self.identity {
+            self.generators.push(generator);
+            self.add_strong_generator(residue, 0, depth);
+            self.schreier_sims();
+        }
+    }
+
+    /// Completes the strong generating set from the deepest level upwards.
+    fn schreier_sims(&mut self) {
+        let mut remaining = self.levels.len();
+
+        while remaining > 0 {
+            let depth = remaining - 1;
+
+            match self.find_residue(depth) {
+                Some((residue, residue_depth)) => {
+                    self.add_strong_generator(residue, depth + 1, residue_depth);
+                    remaining = residue_depth + 1;
+                }
+                None => remaining -= 1,
+            }
+        }
+    }
+
+    /// Finds a Schreier generator at a level that does not sift through the deeper levels.
+    fn find_residue(&self, depth: usize) -> Option<(P, usize)> {
+        let level = &self.levels[depth];
+
+        level.orbit.iter().find_map(|&point| {
+            let coset = level.transversal[point].as_ref().unwrap();
+
+            level.generators.iter().find_map(|&index| {
+                let generator = &self.strong_generators[index];
+                let image = image(generator, point);
+                let inverse = level.transversal[image].as_ref().unwrap().inverse();
+
+                // the Schreier generator fixes the base point
+                let schreier = &(&inverse * generator) * coset;
+                let (residue, residue_depth) = self.sift_with(schreier, depth + 1, |_| {});
+                (residue != self.identity).then_some((residue, residue_depth))
+            })
+        })
+    }
+
+    /// Adds a strong generator fixing the base points before `depth` to the levels from `first` to `depth`.
+    fn add_strong_generator(&mut self, generator: P, first: usize, depth: usize) {
+        // extend the base if the generator fixes all base points
+        if depth == self.levels.len() {
+            let point = (0..self.size)
+                .find(|&point| image(&generator, point) != point)
+                .unwrap();
+            self.push_level(point);
+        }
+
+        let index = self.strong_generators.len();
+        self.strong_generators.push(generator);
+
+        let strong_generators = &self.strong_generators;
+        self.levels[first..=depth].iter_mut().for_each(|level| {
+            level.generators.push(index);
+            level.extend_orbit(strong_generators);
+        });
+    }
+
+    fn push_level(&mut self, point: usize) {
+        let mut transversal = vec![None; self.size];
+        transversal[point] = Some(self.identity.clone());
+
+        self.levels.push(Level {
+            point,
+            generators: vec![],
+            transversal,
+            orbit: vec![point],
+        });
+    }
+
+    /// Divides the element by transversal elements from a level downwards, and returns the
+    /// residue with the level where it stops.
+    fn sift_with<F>(&self, mut element: P, first: usize, mut f: F) -> (P, usize)
+    where
+        F: FnMut(&P),
+    {
+        for (depth, level) in self.levels.iter().enumerate().skip(first) {
+            let coset = match &level.transversal[image(&element, level.point)] {
+                Some(coset) => coset,
+                None => return (element, depth),
+            };
+            f(coset);
+            element = &coset.inverse() * &element;
+        }
+        (element, self.levels.len())
+    }
+}
+
+/// An iterator over the elements of a permutation group.
+///
+/// Each element is built as a unique product of transversal elements of the stabilizer chain.
+#[derive(Debug, Clone)]
+pub struct Elements<'a, P> {
+    group: &'a PermGroup<P>,
+    choices: Option<Vec<usize>>,
+}
+
+impl<'a, P> Iterator for Elements<'a, P>
+where
+    P: Permutation + Clone + PartialEq,
+    for<'b> &'b P: Mul<&'b P, Output = P>,
+{
+    type Item = P;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let choices = self.choices.as_mut()?;
+        let element = self.group.element_at(choices.iter().copied());
+
+        // advance the choices as a mixed-radix counter
+        let lens = self.group.basic_orbit_lens();
+        let carried = choices.iter_mut().zip(lens).rev().all(|(choice, len)| {
+            *choice += 1;
+            if *choice == len {
+                *choice = 0;
+                true
+            } else {
+                false
+            }
+        });
+        if carried {
+            self.choices = None;
+        }
+
+        Some(element)
+    }
+}
+
+impl<'a, P> iter::FusedIterator for Elements<'a, P>
+where
+    P: Permutation + Clone + PartialEq,
+    for<'b> &'b P: Mul<&'b P, Output = P>,
+{
+}
+
+/// The Schreier vector of an orbit, which labels each orbit point by the generator
+/// reaching it from an earlier point in breadth-first order.
+#[derive(Debug, Clone)]
+pub struct SchreierVector<'a, P> {
+    group: &'a PermGroup<P>,
+    root: usize,
+    labels: Vec<Option<usize>>,
+    orbit: Vec<usize>,
+}
+
+impl<'a, P> SchreierVector<'a, P>
+where
+    P: Permutation + Clone + PartialEq,
+    for<'b> &'b P: Mul<&'b P, Output = P>,
+{
+    /// Gets the point whose orbit is labeled.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Gets the orbit in breadth-first order.
+    pub fn orbit(&self) -> &[usize] {
+        &self.orbit
+    }
+
+    /// Gets the index of generator reaching the point, which is `None` for the root and points outside the orbit.
+    pub fn label(&self, point: usize) -> Option<usize> {
+        self.labels.get(point).copied().flatten()
+    }
+
+    /// Checks if the point is in the orbit.
+    pub fn contains(&self, point: usize) -> bool {
+        point == self.root || self.label(point).is_some()
+    }
+
+    /// Builds the element mapping the root to the point by tracing the labels back to the root.
+    pub fn element(&self, point: usize) -> Option<P> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        let mut element = self.group.identity.clone();
+        let mut current = point;
+        while current != self.root {
+            let generator = &self.group.generators[self.labels[current].unwrap()];
+            element = &element * generator;
+            current = preimage(generator, current);
+        }
+        Some(element)
+    }
+
+    /// Builds the transversal, the elements mapping the root to each orbit point, indexed by point.
+    pub fn transversal(&self) -> Vec<Option<P>> {
+        let mut transversal = vec![None; self.labels.len()];
+        transversal[self.root] = Some(self.group.identity.clone());
+
+        self.orbit.iter().skip(1).for_each(|&point| {
+            let generator = &self.group.generators[self.labels[point].unwrap()];
+            let parent = preimage(generator, point);
+            let coset = generator * transversal[parent].as_ref().unwrap();
+            transversal[point] = Some(coset);
+        });
+        transversal
+    }
+}
+
+impl<P> Level<P>
+where
+    P: Permutation + Clone,
+    for<'a> &'a P: Mul<&'a P, Output = P>,
+{
+    /// Extends the orbit and transversal to be closed under the level generators.
+    fn extend_orbit(&mut self, strong_generators: &[P]) {
+        let mut nth = 0;
+
+        while nth < self.orbit.len() {
+            let point = self.orbit[nth];
+            nth += 1;
+
+            let Self {
+                generators,
+                transversal,
+                orbit,
+                ..
+            } = self;
+
+            generators.iter().for_each(|&index| {
+                let generator = &strong_generators[index];
+                let image = image(generator, point);
+
+                if transversal[image].is_none() {
+                    let coset = generator * transversal[point].as_ref().unwrap();
+                    transversal[image] = Some(coset);
+                    orbit.push(image);
+                }
+            });
+        }
+    }
+}
+
+fn image<P>(perm: &P, point: usize) -> usize
+where
+    P: Permutation,
+{
+    perm.indices()[point]
+}
+
+fn preimage<P>(perm: &P, point: usize) -> usize
+where
+    P: Permutation,
+{
+    perm.indices()
+        .iter()
+        .position(|&image| image == point)
+        .unwrap()
+}
+
+fn symmetric_generators(size: usize) -> Vec<Vec<usize>> {
+    match size {
+        0 | 1 => vec![],
+        _ => vec![rotation(size), swap(size, 0, 1)],
+    }
+}
+
+fn alternating_generators(size: usize) -> Vec<Vec<usize>> {
+    (2..size)
+        .map(|last| {
+            // the 3-cycle (0 1 last)
+            let mut indices: Vec<_> = (0..size).collect();
+            indices[0] = 1;
+            indices[1] = last;
+            indices[last] = 0;
+            indices
+        })
+        .collect()
+}
+
+fn cyclic_generators(size: usize) -> Vec<Vec<usize>> {
+    match size {
+        0 | 1 => vec![],
+        _ => vec![rotation(size)],
+    }
+}
+
+fn dihedral_generators(size: usize) -> Vec<Vec<usize>> {
+    match size {
+        0 | 1 => vec![],
+        _ => {
+            let reflection = (0..size).map(|point| (size - point) % size).collect();
+            vec![rotation(size), reflection]
+        }
+    }
+}
+
+/// The rotation mapping each point to the next one.
+fn rotation(size: usize) -> Vec<usize> {
+    (0..size).map(|point| (point + 1) % size).collect()
+}
+
+fn swap(size: usize, first: usize, second: usize) -> Vec<usize> {
+    let mut indices: Vec<_> = (0..size).collect();
+    indices.swap(first, second);
+    indices
+}
+
+fn normalize_set(set: &[usize], size: usize) -> Vec<usize> {
+    let mut set = set.to_vec();
+    set.sort_unstable();
+    set.dedup();
+    assert!(
+        set.iter().all(|&point| point < size),
+        "the point is out of range"
+    );
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_cycles::PermFromCycles, from_indices::PermFromIndices};
+
+    fn mathieu_generators() -> Vec<PermD> {
+        vec![
+            PermD::from_cycles(&[vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]]).unwrap(),
+            PermD::from_cycles(&[vec![2, 6, 10, 7], vec![3, 9, 4, 5]]).unwrap(),
+            PermD::from_cycles(&[
+                vec![0, 11],
+                vec![1, 10],
+                vec![2, 5],
+                vec![3, 7],
+                vec![4, 8],
+                vec![6, 9],
+            ])
+            .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn group_order() {
+        let trivial = PermGroup::<PermS<4>>::new_static(&[]);
+        assert_eq!(trivial.order(), Some(1));
+        assert!(trivial.base().is_empty());
+        assert!(trivial.contains(&PermS::identity()));
+        assert!(!trivial.contains(&PermS::cycle()));
+
+        let symmetric =
+            PermGroup::<PermS<8>>::new_static(&[PermS::cycle(), PermS::swap(0, 1).unwrap()]);
+        assert_eq!(symmetric.order(), Some(40320));
+
+        let cube = PermGroup::<PermS<8>>::new_static(&[
+            PermS::from_cycles(&[[0, 1, 3, 2], [4, 5, 7, 6]]).unwrap(),
+            PermS::from_cycles(&[[0, 1, 5, 4], [2, 3, 7, 6]]).unwrap(),
+        ]);
+        assert_eq!(cube.order(), Some(24));
+
+        // the Mathieu groups M11 and M12
+        let generators = mathieu_generators();
+        let m11 = PermGroup::new(
+            11,
+            &[
+                generators[0].to_size(11).unwrap(),
+                generators[1].to_size(11).unwrap(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(m11.order(), Some(7920));
+
+        let m12 = PermGroup::new(
+            12,
+            &[
+                generators[0].to_size(12).unwrap(),
+                generators[1].to_size(12).unwrap(),
+                generators[2].clone(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(m12.order(), Some(95040));
+
+        assert!(PermGroup::new(12, &generators[..1]).is_err());
+    }
+
+    #[test]
+    fn group_membership() {
+        let generators = [
+            PermD::from_indices(vec![1, 2, 3, 4, 5, 0]).unwrap(),
+            PermD::from_indices(vec![5, 4, 3, 2, 1, 0]).unwrap(),
+        ];
+        let dihedral = PermGroup::new(6, &generators).unwrap();
+        assert_eq!(dihedral.order(), Some(12));
+
+        let rotation = &generators[0] * &generators[0];
+        let reflection = &rotation * &generators[1];
+        [rotation, reflection].iter().for_each(|member| {
+            let factors = dihedral.factorize(member).unwrap();
+            let product: PermD = factors
+                .iter()
+                .fold(PermD::identity(6), |product, factor| &product * factor);
+            assert_eq!(&product, member);
+        });
+
+        assert!(!dihedral.contains(&PermD::swap(6, 0, 1).unwrap()));
+        assert_eq!(dihedral.generators(), &generators);
+        assert!(!dihedral.contains(&PermD::identity(5)));
+        assert!(dihedral
+            .strong_generators()
+            .iter()
+            .all(|generator| dihedral.contains(generator)));
+    }
+
+    #[test]
+    fn group_orbits() {
+        let generators = [
+            PermD::from_cycles(&[vec![0, 1, 2]])
+                .unwrap()
+                .to_size(7)
+                .unwrap(),
+            PermD::from_cycles(&[vec![3, 4], vec![5, 6]]).unwrap(),
+        ];
+        let group = PermGroup::new(7, &generators).unwrap();
+        assert_eq!(group.orbit(1), vec![1, 2, 0]);
+        assert_eq!(group.orbits(), vec![vec![0, 1, 2], vec![3, 4], vec![5, 6]]);
+        assert_eq!(
+            group.set_orbit(&[4, 0, 0]),
+            vec![
+                vec![0, 4],
+                vec![1, 4],
+                vec![0, 3],
+                vec![2, 4],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+
+        let schreier_vector = group.schreier_vector(0);
+        assert_eq!(schreier_vector.orbit(), &[0, 1, 2]);
+        assert_eq!(schreier_vector.label(0), None);
+        assert_eq!(schreier_vector.label(2), Some(0));
+        assert!(!schreier_vector.contains(3));
+        assert!(schreier_vector.element(3).is_none());
+
+        let transversal = schreier_vector.transversal();
+        [0, 1, 2].iter().for_each(|&point| {
+            let element = schreier_vector.element(point).unwrap();
+            assert_eq!(element.indices()[0], point);
+            assert_eq!(transversal[point].as_ref(), Some(&element));
+        });
+    }
+
+    #[test]
+    fn group_stabilizers() {
+        let generators = mathieu_generators();
+        let m11 = PermGroup::new(
+            11,
+            &[
+                generators[0].to_size(11).unwrap(),
+                generators[1].to_size(11).unwrap(),
+            ],
+        )
+        .unwrap();
+
+        // the orbit-stabilizer theorem
+        (0..11).for_each(|point| {
+            let stabilizer = m11.stabilizer(point);
+            assert_eq!(stabilizer.order(), Some(720));
+            assert!(stabilizer
+                .generators()
+                .iter()
+                .all(|generator| generator.indices()[point] == point && m11.contains(generator)));
+        });
+        assert_eq!(m11.stabilizer(0).stabilizer(1).order(), Some(72));
+
+        let symmetric =
+            PermGroup::<PermS<5>>::new_static(&[PermS::cycle(), PermS::swap(0, 1).unwrap()]);
+        assert_eq!(symmetric.set_stabilizer(&[3, 1]).order(), Some(12));
+        assert_eq!(symmetric.set_stabilizer(&[]).order(), Some(120));
+        assert_eq!(
+            symmetric.set_stabilizer(&[0, 1, 2, 3, 4]).order(),
+            Some(120)
+        );
+
+        let hexagon = PermGroup::<PermS<6>>::new_static(&[
+            PermS::cycle(),
+            PermS::from_indices([5, 4, 3, 2, 1, 0]).unwrap(),
+        ]);
+        let stabilizer = hexagon.set_stabilizer(&[0, 3]);
+        assert_eq!(stabilizer.order(), Some(4));
+        assert!(stabilizer.generators().iter().all(|generator| {
+            let mut images = [generator.indices()[0], generator.indices()[3]];
+            images.sort_unstable();
+            images == [0, 3]
+        }));
+        assert_eq!(hexagon.set_stabilizer(&[0, 1, 3]).order(), Some(1));
+
+        // the search skips prefixes already in the stabilizer found so far
+        let set: Vec<_> = (0..8).collect();
+        let stabilizer = PermGroup::symmetric(16).set_stabilizer(&set);
+        assert_eq!(stabilizer.order(), Some(40320 * 40320));
+        assert!(stabilizer.generators().len() <= 32);
+    }
+
+    #[test]
+    fn named_groups() {
+        assert_eq!(PermGroup::symmetric(6).order(), Some(720));
+        assert_eq!(PermGroup::alternating(6).order(), Some(360));
+        assert_eq!(PermGroup::cyclic(6).order(), Some(6));
+        assert_eq!(PermGroup::dihedral(6).order(), Some(12));
+        assert_eq!(PermGroup::dihedral(2).order(), Some(2));
+        assert_eq!(
+            PermGroup::symmetric(34).order(),
+            (1..=34u128).try_fold(1u128, |product, factor| product.checked_mul(factor))
+        );
+        [0, 1].iter().for_each(|&size| {
+            assert_eq!(PermGroup::symmetric(size).order(), Some(1));
+            assert_eq!(PermGroup::alternating(size).order(), Some(1));
+            assert_eq!(PermGroup::cyclic(size).order(), Some(1));
+            assert_eq!(PermGroup::dihedral(size).order(), Some(1));
+        });
+
+        assert_eq!(PermGroup::<PermS<5>>::symmetric_static().order(), Some(120));
+        assert_eq!(
+            PermGroup::<PermS<5>>::alternating_static().order(),
+            Some(60)
+        );
+        assert_eq!(PermGroup::<PermS<5>>::cyclic_static().order(), Some(5));
+        assert_eq!(PermGroup::<PermS<5>>::dihedral_static().order(), Some(10));
+
+        let product =
+            PermGroup::symmetric(3).direct_product(&PermGroup::<PermS<4>>::cyclic_static());
+        assert_eq!(product.size(), 7);
+        assert_eq!(product.order(), Some(24));
+        assert_eq!(product.orbits(), vec![vec![0, 1, 2], vec![3, 4, 5, 6]]);
+    }
+
+    #[test]
+    fn group_elements() {
+        let alternating = PermGroup::alternating(5);
+        let elements: BTreeSet<_> = alternating
+            .elements()
+            .map(|element| element.indices().to_vec())
+            .collect();
+        assert_eq!(elements.len(), 60);
+        assert!(alternating
+            .elements()
+            .all(|element| element.is_even() && alternating.contains(&element)));
+
+        let dihedral = PermGroup::<PermS<4>>::dihedral_static();
+        let elements: Vec<_> = dihedral.elements().collect();
+        assert_eq!(elements.len(), 8);
+        assert_eq!(elements[0], PermS::<4>::identity());
+        assert!(elements
+            .iter()
+            .all(|element| PermS::<4>::all().filter(|perm| perm == element).count() == 1));
+
+        assert_eq!(PermGroup::symmetric(0).elements().count(), 1);
+        assert_eq!(PermGroup::symmetric(4).elements().count(), 24);
+    }
+}
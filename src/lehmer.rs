@@ -0,0 +1,357 @@
+use crate::{error::PermError, fenwick::Fenwick};
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Gets the Lehmer code, which counts the smaller indices to the right of each position.
+        pub fn lehmer_code(&self) -> [usize; SIZE] {
+            let mut code = [0; SIZE];
+            encode(
+                &self.indices,
+                &mut code,
+                &mut [0; SIZE],
+                Variant::RIGHT_LEHMER,
+            );
+            code
+        }
+
+        /// Gets the left Lehmer code, which counts the larger indices to the left of each position.
+        pub fn left_lehmer_code(&self) -> [usize; SIZE] {
+            let mut code = [0; SIZE];
+            encode(
+                &self.indices,
+                &mut code,
+                &mut [0; SIZE],
+                Variant::LEFT_LEHMER,
+            );
+            code
+        }
+
+        /// Gets the inversion vector, which counts the larger indices to the left of each index.
+        pub fn inversion_vector(&self) -> [usize; SIZE] {
+            let mut code = [0; SIZE];
+            encode(&self.indices, &mut code, &mut [0; SIZE], Variant::INVERSION);
+            code
+        }
+
+        /// Gets the right inversion vector, which counts the smaller indices to the right of each index.
+        pub fn right_inversion_vector(&self) -> [usize; SIZE] {
+            let mut code = [0; SIZE];
+            encode(
+                &self.indices,
+                &mut code,
+                &mut [0; SIZE],
+                Variant::RIGHT_INVERSION,
+            );
+            code
+        }
+
+        /// Builds a permutation from its Lehmer code, where the digit at position `i` is less than `SIZE - i`.
+        pub fn from_lehmer_code(code: &[usize; SIZE]) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            decode(
+                code,
+                &mut perm.indices,
+                &mut [0; SIZE],
+                Variant::RIGHT_LEHMER,
+            )?;
+            Ok(perm)
+        }
+
+        /// Builds a permutation from its left Lehmer code, where the digit at position `i` is at most `i`.
+        pub fn from_left_lehmer_code(code: &[usize; SIZE]) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            decode(
+                code,
+                &mut perm.indices,
+                &mut [0; SIZE],
+                Variant::LEFT_LEHMER,
+            )?;
+            Ok(perm)
+        }
+
+        /// Builds a permutation from its inversion vector, where the digit at position `i` is less than `SIZE - i`.
+        pub fn from_inversion_vector(code: &[usize; SIZE]) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            decode(code, &mut perm.indices, &mut [0; SIZE], Variant::INVERSION)?;
+            Ok(perm)
+        }
+
+        /// Builds a permutation from its right inversion vector, where the digit at position `i` is at most `i`.
+        pub fn from_right_inversion_vector(code: &[usize; SIZE]) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            decode(
+                code,
+                &mut perm.indices,
+                &mut [0; SIZE],
+                Variant::RIGHT_INVERSION,
+            )?;
+            Ok(perm)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{from_indices::PermFromIndices, perm_trait::Permutation};
+
+        #[test]
+        fn static_lehmer_code() {
+            let perm = PermS::from_indices([2, 0, 3, 1]).unwrap();
+            assert_eq!(perm.lehmer_code(), [2, 0, 1, 0]);
+            assert_eq!(perm.left_lehmer_code(), [0, 1, 0, 2]);
+            assert_eq!(perm.inversion_vector(), [1, 2, 0, 0]);
+            assert_eq!(perm.right_inversion_vector(), [0, 0, 2, 1]);
+
+            // the inversion vector is the Lehmer code of inverse
+            assert_eq!(perm.inverse().lehmer_code(), perm.inversion_vector());
+            assert_eq!(
+                perm.inverse().left_lehmer_code(),
+                perm.right_inversion_vector()
+            );
+
+            (0..120).for_each(|rank| {
+                let perm = PermS::<5>::from_rank(rank).unwrap();
+                assert_eq!(PermS::from_lehmer_code(&perm.lehmer_code()).unwrap(), perm);
+                assert_eq!(
+                    PermS::from_left_lehmer_code(&perm.left_lehmer_code()).unwrap(),
+                    perm
+                );
+                assert_eq!(
+                    PermS::from_inversion_vector(&perm.inversion_vector()).unwrap(),
+                    perm
+                );
+                assert_eq!(
+                    PermS::from_right_inversion_vector(&perm.right_inversion_vector()).unwrap(),
+                    perm
+                );
+            });
+
+            assert_eq!(
+                PermS::<0>::from_lehmer_code(&[]).unwrap(),
+                PermS::<0>::identity()
+            );
+            assert_eq!(
+                PermS::from_lehmer_code(&[3, 3, 1, 0]),
+                Err(PermError::InvalidDigit {
+                    position: 1,
+                    digit: 3,
+                    bound: 3
+                })
+            );
+            assert_eq!(
+                PermS::from_right_inversion_vector(&[0, 2, 0]),
+                Err(PermError::InvalidDigit {
+                    position: 1,
+                    digit: 2,
+                    bound: 2
+                })
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{common::*, perm_type::PermD};
+
+    impl PermD {
+        /// Gets the Lehmer code, which counts the smaller indices to the right of each position.
+        pub fn lehmer_code(&self) -> Vec<usize> {
+            self.encode(Variant::RIGHT_LEHMER)
+        }
+
+        /// Gets the left Lehmer code, which counts the larger indices to the left of each position.
+        pub fn left_lehmer_code(&self) -> Vec<usize> {
+            self.encode(Variant::LEFT_LEHMER)
+        }
+
+        /// Gets the inversion vector, which counts the larger indices to the left of each index.
+        pub fn inversion_vector(&self) -> Vec<usize> {
+            self.encode(Variant::INVERSION)
+        }
+
+        /// Gets the right inversion vector, which counts the smaller indices to the right of each index.
+        pub fn right_inversion_vector(&self) -> Vec<usize> {
+            self.encode(Variant::RIGHT_INVERSION)
+        }
+
+        /// Builds a permutation from its Lehmer code, where the digit at position `i` is less than `len - i`.
+        pub fn from_lehmer_code(code: &[usize]) -> Result<Self, PermError> {
+            Self::decode(code, Variant::RIGHT_LEHMER)
+        }
+
+        /// Builds a permutation from its left Lehmer code, where the digit at position `i` is at most `i`.
+        pub fn from_left_lehmer_code(code: &[usize]) -> Result<Self, PermError> {
+            Self::decode(code, Variant::LEFT_LEHMER)
+        }
+
+        /// Builds a permutation from its inversion vector, where the digit at position `i` is less than `len - i`.
+        pub fn from_inversion_vector(code: &[usize]) -> Result<Self, PermError> {
+            Self::decode(code, Variant::INVERSION)
+        }
+
+        /// Builds a permutation from its right inversion vector, where the digit at position `i` is at most `i`.
+        pub fn from_right_inversion_vector(code: &[usize]) -> Result<Self, PermError> {
+            Self::decode(code, Variant::RIGHT_INVERSION)
+        }
+
+        fn encode(&self, variant: Variant) -> Vec<usize> {
+            let len = self.indices.len();
+            let mut code = vec![0; len];
+            encode(&self.indices, &mut code, &mut vec![0; len], variant);
+            code
+        }
+
+        fn decode(code: &[usize], variant: Variant) -> Result<Self, PermError> {
+            let len = code.len();
+            let mut perm = Self::identity(len);
+            decode(code, &mut perm.indices, &mut vec![0; len], variant)?;
+            Ok(perm)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::perm_trait::Permutation;
+        use rand::prelude::*;
+
+        #[test]
+        fn dynamic_lehmer_code() {
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(4096);
+            perm.indices.shuffle(&mut rng);
+
+            let code = perm.lehmer_code();
+            assert_eq!(PermD::from_lehmer_code(&code).unwrap(), perm);
+            assert_eq!(
+                code.iter().sum::<usize>(),
+                perm.left_lehmer_code().iter().sum::<usize>()
+            );
+            assert_eq!(perm.inverse().lehmer_code(), perm.inversion_vector());
+            assert_eq!(
+                PermD::from_left_lehmer_code(&perm.left_lehmer_code()).unwrap(),
+                perm
+            );
+            assert_eq!(
+                PermD::from_inversion_vector(&perm.inversion_vector()).unwrap(),
+                perm
+            );
+            assert_eq!(
+                PermD::from_right_inversion_vector(&perm.right_inversion_vector()).unwrap(),
+                perm
+            );
+
+            // any vector with digits in bounds is a valid code
+            let code: Vec<_> = (0..100).map(|index| rng.gen_range(0..=index)).collect();
+            let perm = PermD::from_left_lehmer_code(&code).unwrap();
+            assert_eq!(perm.left_lehmer_code(), code);
+            assert_eq!(
+                PermD::from_rank(5, 119).unwrap().lehmer_code(),
+                vec![4, 3, 2, 1, 0]
+            );
+            assert!(PermD::from_inversion_vector(&[0, 1]).is_err());
+        }
+    }
+}
+
+/// The code variant, which counts either smaller indices to the right or larger indices to the left,
+/// and is indexed by either position or index.
+#[derive(Debug, Clone, Copy)]
+struct Variant {
+    right: bool,
+    by_index: bool,
+}
+
+impl Variant {
+    const RIGHT_LEHMER: Self = Self {
+        right: true,
+        by_index: false,
+    };
+    const LEFT_LEHMER: Self = Self {
+        right: false,
+        by_index: false,
+    };
+    const INVERSION: Self = Self {
+        right: false,
+        by_index: true,
+    };
+    const RIGHT_INVERSION: Self = Self {
+        right: true,
+        by_index: true,
+    };
+}
+
+/// Computes the code of indices in O(n log n) time.
+fn encode(indices: &[usize], code: &mut [usize], tree: &mut [usize], variant: Variant) {
+    let len = indices.len();
+    let mut inserted = Fenwick::empty(tree);
+
+    (0..len).for_each(|step| {
+        let position = match variant.right {
+            true => len - 1 - step,
+            false => step,
+        };
+        let index = indices[position];
+
+        // count the seen indices on the right or on the left
+        let smaller = inserted.count_less(index);
+        let digit = match variant.right {
+            true => smaller,
+            false => step - smaller,
+        };
+        inserted.insert(index);
+
+        match variant.by_index {
+            true => code[index] = digit,
+            false => code[position] = digit,
+        }
+    });
+}
+
+/// Builds the indices from the code in O(n log n) time.
+fn decode(
+    code: &[usize],
+    indices: &mut [usize],
+    tree: &mut [usize],
+    variant: Variant,
+) -> Result<(), PermError> {
+    let len = code.len();
+    let mut free = Fenwick::full(tree);
+
+    // the slot is picked among free indices by position, or free positions by index
+    let forward = variant.right != variant.by_index;
+
+    (0..len).try_for_each(|step| {
+        let position = match forward {
+            true => step,
+            false => len - 1 - step,
+        };
+        let digit = code[position];
+        let bound = len - step;
+
+        if digit >= bound {
+            return Err(PermError::InvalidDigit {
+                position,
+                digit,
+                bound,
+            });
+        }
+
+        let slot = match forward {
+            true => free.select(digit),
+            false => free.select(bound - 1 - digit),
+        };
+        free.remove(slot);
+
+        match variant.by_index {
+            true => indices[slot] = position,
+            false => indices[position] = slot,
+        }
+        Ok(())
+    })
+}
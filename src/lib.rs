@@ -10,8 +10,10 @@
 //! ```
 //!
 //! It has the following cargo features.
-//! - **std** (default): enable the standard library.
+//! - **std** (default): enable the standard library. It implies **alloc**.
+//! - **alloc**: enable the dynamic permutation `PermD` with the `alloc` crate.
 //! - **rand** (default): enable random sampling of permutation.
+//! - **bigint**: enable ranking permutations of arbitrary sizes with `num-bigint`. It implies **alloc**.
 //!
 //! To restrict the crate to `no_std`, you can disable the default features.
 //!
@@ -20,6 +22,13 @@
 //! rusty-perm = { version = "0.2", default-features = false }
 //! ```
 //!
+//! To use `PermD` on `no_std` targets with a global allocator, enable the **alloc** feature.
+//!
+//! ```toml
+//! [dependencies]
+//! rusty-perm = { version = "0.2", default-features = false, features = ["alloc"] }
+//! ```
+//!
 //! ## Import this crate
 //!
 //! To import members from this crate,
@@ -86,6 +95,85 @@
 //! assert_eq!(array, [3, -9, -5]);
 //! ```
 //!
+//! Storage without a slice view can be permuted by a swap callback.
+//!
+//! ```rust
+//! use rusty_perm::{prelude::*, PermD};
+//! use std::collections::VecDeque;
+//! let perm = PermD::from_indices([2, 0, 1]).unwrap();
+//!
+//! let mut deque = VecDeque::from(vec![-9, -5, 3]);
+//! perm.apply_with(|first, second| deque.swap(first, second));
+//! assert_eq!(deque, [3, -9, -5]);
+//! ```
+//!
+//! ## Build by cycles
+//! The permutation can be constructed from disjoint cycles, or from a product
+//! of overlapping cycles.
+//!
+//! ```rust
+//! use rusty_perm::{prelude::*, PermD, PermS};
+//! let perm = PermS::<5>::from_cycles(&[&[0, 2, 4][..], &[1, 3]]).unwrap();
+//! assert_eq!(perm, PermD::from_indices([2, 3, 4, 1, 0]).unwrap());
+//!
+//! let perm = PermD::from_cycle_product(&[[0, 1], [1, 2]]).unwrap();
+//! assert_eq!(perm, PermD::from_cycles(&[[0, 1, 2]]).unwrap());
+//! ```
+//!
+//! ## Cycle decomposition
+//! The disjoint cycles are iterated in canonical form, each starting from
+//! its smallest element.
+//!
+//! ```rust
+//! use rusty_perm::{prelude::*, PermS};
+//!
+//! let perm = PermS::from_indices([2, 3, 4, 1, 0, 5]).unwrap();
+//! let mut cycles = perm.cycles().skip_fixed_points();
+//! assert!(cycles.next().unwrap().eq([0, 2, 4]));
+//! assert!(cycles.next().unwrap().eq([1, 3]));
+//! assert!(cycles.next().is_none());
+//! ```
+//!
+//! ## Text notations
+//! The permutation is printed in one-line notation by default, and can be
+//! formatted in cycle or two-line notation. Both notations can be parsed back.
+//!
+//! ```rust
+//! use rusty_perm::{prelude::*, Notation, PermD};
+//!
+//! let perm: PermD = "(0 2 1)(3 4)".parse().unwrap();
+//! assert_eq!(perm.to_string(), "[2 0 1 4 3]");
+//! assert_eq!(perm.display(Notation::Cycle).to_string(), "(0 2 1)(3 4)");
+//! assert_eq!("[2 0 1 4 3]".parse::<PermD>().unwrap(), perm);
+//! ```
+//!
+//! ## Rank in lexicographic order
+//! The permutations of the same size are ranked in lexicographic order of indices.
+//!
+//! ```rust
+//! use rusty_perm::{prelude::*, PermD, PermS};
+//!
+//! let perm = PermS::from_indices([1, 0, 3, 2]).unwrap();
+//! assert_eq!(perm.rank().unwrap(), 7);
+//! assert_eq!(PermD::from_rank(4, 7).unwrap(), perm);
+//! ```
+//!
+//! ## Permutation groups
+//! The group generated by permutations supports order computation and membership testing.
+//!
+//! ```rust
+//! use rusty_perm::{PermD, PermGroup};
+//!
+//! let generators = [PermD::cycle(4), PermD::swap(4, 0, 1).unwrap()];
+//! let group = PermGroup::new(4, &generators).unwrap();
+//! assert_eq!(group.order(), Some(24));
+//! assert!(group.contains(&PermD::swap(4, 2, 3).unwrap()));
+//!
+//! // Standard groups can be built directly and enumerated
+//! let dihedral = PermGroup::dihedral(5);
+//! assert_eq!(dihedral.elements().count(), 10);
+//! ```
+//!
 //! ## Inverse and composition
 //! The example demonstrates the inverse and composition of permutations.
 //!
@@ -101,29 +189,63 @@
 //! assert_eq!(PermD::identity(3), composition);
 //! ```
 
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// Re-export of common traits.
 pub mod prelude {
     pub use super::{
-        apply::PermApply, from_indices::PermFromIndices, from_sorting::PermFromSorting,
-        perm_trait::Permutation, product::PermProduct,
+        apply::{PermApply, PermApplyInverse},
+        distance::PermDistance,
+        from_cycles::PermFromCycles,
+        from_indices::PermFromIndices,
+        from_sorting::PermFromSorting,
+        perm_trait::Permutation,
+        product::PermProduct,
     };
 }
 
 mod apply;
+mod centralizer;
+mod columns;
 mod common;
+mod conjugacy;
+mod cycles;
+mod distance;
+mod enumeration;
+mod error;
+mod fenwick;
+mod from_cycles;
 mod from_indices;
 mod from_sorting;
+mod group;
+mod lehmer;
+mod minimal_change;
+mod notation;
 mod perm_trait;
 mod perm_type;
 mod product;
 mod rand;
+mod rank;
 pub mod size;
 
+#[cfg(feature = "rand")]
+pub use self::rand::*;
 pub use apply::*;
+pub use columns::*;
+pub use cycles::*;
+pub use distance::*;
+pub use enumeration::*;
+pub use error::PermError;
+pub use from_cycles::*;
 pub use from_indices::*;
 pub use from_sorting::*;
+#[cfg(feature = "alloc")]
+pub use group::*;
+pub use minimal_change::*;
+pub use notation::*;
 pub use perm_trait::*;
 pub use perm_type::*;
 pub use product::*;
@@ -0,0 +1,307 @@
+use crate::{
+    apply::PermApply,
+    common::*,
+    error::PermError,
+    perm_type::Perm,
+    size::{PermSize, Static},
+};
+
+/// The swap of two positions, reported by minimal-change enumerations.
+///
+/// Applying it to a buffer permuted by the previous permutation gives the buffer
+/// permuted by the next permutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transposition {
+    pub first: usize,
+    pub second: usize,
+}
+
+impl<T> PermApply<[T]> for Transposition {
+    type Output = Result<(), PermError>;
+
+    fn apply(&self, input: &mut [T]) -> Self::Output {
+        let len = input.len();
+        [self.first, self.second]
+            .iter()
+            .enumerate()
+            .try_for_each(|(position, &index)| {
+                if index >= len {
+                    return Err(PermError::IndexOutOfRange {
+                        position,
+                        index,
+                        len,
+                    });
+                }
+                Ok(())
+            })?;
+        input.swap(self.first, self.second);
+        Ok(())
+    }
+}
+
+impl<T, const SIZE: usize> PermApply<[T; SIZE]> for Transposition {
+    type Output = Result<(), PermError>;
+
+    fn apply(&self, input: &mut [T; SIZE]) -> Self::Output {
+        self.apply(input.as_mut())
+    }
+}
+
+/// An iterator of permutations by Heap's algorithm, where consecutive permutations differ by a transposition.
+///
+/// It starts from the identity, and yields the transposition performed at each step until
+/// all permutations are visited. The current permutation is available by [perm](HeapPerms::perm).
+pub struct HeapPerms<S>
+where
+    S: PermSize,
+{
+    perm: Perm<S>,
+    counters: S::Container,
+    level: usize,
+}
+
+impl<S> HeapPerms<S>
+where
+    S: PermSize,
+{
+    /// Gets the current permutation.
+    pub fn perm(&self) -> &Perm<S> {
+        &self.perm
+    }
+}
+
+impl<S> Iterator for HeapPerms<S>
+where
+    S: PermSize,
+{
+    type Item = Transposition;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.perm.indices.as_mut();
+        let counters = self.counters.as_mut();
+        let len = indices.len();
+
+        while self.level < len {
+            let level = self.level;
+
+            if counters[level] < level {
+                let other = match level % 2 {
+                    0 => 0,
+                    _ => counters[level],
+                };
+                indices.swap(other, level);
+                counters[level] += 1;
+                self.level = 1;
+
+                return Some(Transposition {
+                    first: other,
+                    second: level,
+                });
+            }
+
+            counters[level] = 0;
+            self.level += 1;
+        }
+        None
+    }
+}
+
+impl<S> iter::FusedIterator for HeapPerms<S> where S: PermSize {}
+
+/// An iterator of permutations by the Steinhaus–Johnson–Trotter algorithm, where consecutive
+/// permutations differ by an adjacent transposition.
+///
+/// It starts from the identity, and yields the transposition performed at each step until
+/// all permutations are visited. The current permutation is available by [perm](SjtPerms::perm).
+pub struct SjtPerms<S>
+where
+    S: PermSize,
+{
+    perm: Perm<S>,
+    // the direction of each index, 0 for left and 1 for right
+    directions: S::Container,
+}
+
+impl<S> SjtPerms<S>
+where
+    S: PermSize,
+{
+    /// Gets the current permutation.
+    pub fn perm(&self) -> &Perm<S> {
+        &self.perm
+    }
+}
+
+impl<S> Iterator for SjtPerms<S>
+where
+    S: PermSize,
+{
+    type Item = Transposition;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.perm.indices.as_mut();
+        let directions = self.directions.as_mut();
+        let len = indices.len();
+
+        // find the largest mobile index, which points to a smaller neighbor
+        let (position, neighbor) = (0..len)
+            .filter_map(|position| {
+                let index = indices[position];
+                let neighbor = match directions[index] {
+                    0 => position.checked_sub(1)?,
+                    _ => Some(position + 1).filter(|&next| next < len)?,
+                };
+                (indices[neighbor] < index).then_some((position, neighbor))
+            })
+            .max_by_key(|&(position, _)| indices[position])?;
+
+        let index = indices[position];
+        indices.swap(position, neighbor);
+        directions
+            .iter_mut()
+            .skip(index + 1)
+            .for_each(|direction| *direction ^= 1);
+
+        Some(Transposition {
+            first: position.min(neighbor),
+            second: position.max(neighbor),
+        })
+    }
+}
+
+impl<S> iter::FusedIterator for SjtPerms<S> where S: PermSize {}
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Enumerates static permutations by Heap's algorithm.
+        pub fn heap_perms() -> HeapPerms<Static<SIZE>> {
+            HeapPerms {
+                perm: Self::identity(),
+                counters: [0; SIZE],
+                level: 1,
+            }
+        }
+
+        /// Enumerates static permutations by the Steinhaus–Johnson–Trotter algorithm.
+        pub fn sjt_perms() -> SjtPerms<Static<SIZE>> {
+            SjtPerms {
+                perm: Self::identity(),
+                directions: [0; SIZE],
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn static_minimal_change() {
+            let mut heap = PermS::<5>::heap_perms();
+            let mut ranks = [false; 120];
+            let mut data = [10, 11, 12, 13, 14];
+            ranks[0] = true;
+
+            while let Some(swap) = heap.next() {
+                assert!(swap.first < swap.second);
+                swap.apply(&mut data).unwrap();
+
+                let mut expect = [10, 11, 12, 13, 14];
+                heap.perm().apply(&mut expect);
+                assert_eq!(data, expect);
+
+                let rank = heap.perm().rank().unwrap() as usize;
+                assert!(!ranks[rank]);
+                ranks[rank] = true;
+            }
+            assert!(ranks.iter().all(|&visited| visited));
+            assert!(heap.next().is_none());
+
+            let mut sjt = PermS::<4>::sjt_perms();
+            let mut ranks = [false; 24];
+            ranks[0] = true;
+
+            while let Some(swap) = sjt.next() {
+                assert_eq!(swap.first + 1, swap.second);
+                let rank = sjt.perm().rank().unwrap() as usize;
+                assert!(!ranks[rank]);
+                ranks[rank] = true;
+            }
+            assert!(ranks.iter().all(|&visited| visited));
+
+            assert_eq!(PermS::<0>::heap_perms().count(), 0);
+            assert_eq!(PermS::<1>::sjt_perms().count(), 0);
+            assert!(Transposition {
+                first: 0,
+                second: 3
+            }
+            .apply(&mut [0, 1, 2])
+            .is_err());
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{perm_type::PermD, size::Dynamic};
+
+    impl PermD {
+        /// Enumerates dynamic permutations of given size by Heap's algorithm.
+        pub fn heap_perms(size: usize) -> HeapPerms<Dynamic> {
+            HeapPerms {
+                perm: Self::identity(size),
+                counters: vec![0; size],
+                level: 1,
+            }
+        }
+
+        /// Enumerates dynamic permutations of given size by the Steinhaus–Johnson–Trotter algorithm.
+        pub fn sjt_perms(size: usize) -> SjtPerms<Dynamic> {
+            SjtPerms {
+                perm: Self::identity(size),
+                directions: vec![0; size],
+            }
+        }
+    }
+
+    impl<T> PermApply<Vec<T>> for Transposition {
+        type Output = Result<(), PermError>;
+
+        fn apply(&self, input: &mut Vec<T>) -> Self::Output {
+            self.apply(input.as_mut_slice())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::collections::HashSet;
+
+        #[test]
+        fn dynamic_minimal_change() {
+            (0..=6).for_each(|size| {
+                let count: usize = (1..=size).product();
+                assert_eq!(PermD::heap_perms(size).count() + 1, count);
+                assert_eq!(PermD::sjt_perms(size).count() + 1, count);
+            });
+
+            let mut sjt = PermD::sjt_perms(6);
+            let mut data: Vec<_> = (0..6).map(|index| index * 10).collect();
+            let mut visited = HashSet::new();
+            visited.insert(sjt.perm().clone());
+
+            while let Some(swap) = sjt.next() {
+                swap.apply(&mut data).unwrap();
+                let mut expect: Vec<_> = (0..6).map(|index| index * 10).collect();
+                sjt.perm().apply(&mut expect).unwrap();
+                assert_eq!(data, expect);
+                assert!(visited.insert(sjt.perm().clone()));
+            }
+            assert_eq!(visited.len(), 720);
+        }
+    }
+}
@@ -0,0 +1,630 @@
+use crate::{common::*, perm_trait::Permutation, perm_type::Perm, size::PermSize};
+
+/// The text notation of a permutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// The one-line notation listing the indices, such as `[2 0 1]`.
+    OneLine,
+    /// The cycle notation listing every cycle including fixed points, such as
+    /// `(0 2 1)(3 4)(5)`, so that the size is kept in the text.
+    /// The empty permutation is written as `()`.
+    Cycle,
+    /// The two-line notation with the indices `0..n` in the top row and
+    /// the one-line notation in the bottom row.
+    TwoLine,
+}
+
+/// A helper type that formats a permutation in the selected [Notation].
+#[derive(Debug, Clone, Copy)]
+pub struct PermDisplay<'a, P> {
+    perm: &'a P,
+    notation: Notation,
+}
+
+impl<S> Perm<S>
+where
+    S: PermSize,
+    Self: Permutation,
+{
+    /// Returns an object that formats the permutation in a selected notation.
+    pub fn display(&self, notation: Notation) -> PermDisplay<'_, Self> {
+        PermDisplay {
+            perm: self,
+            notation,
+        }
+    }
+}
+
+impl<'a, P> Display for PermDisplay<'a, P>
+where
+    P: Permutation,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let indices = self.perm.indices();
+
+        match self.notation {
+            Notation::OneLine => write_row(f, indices.iter().copied(), 0),
+            Notation::Cycle => {
+                if indices.is_empty() {
+                    return write!(f, "()");
+                }
+                self.perm.cycles().try_for_each(|cycle| {
+                    write!(f, "(")?;
+                    cycle.enumerate().try_for_each(|(nth, index)| {
+                        if nth == 0 {
+                            write!(f, "{}", index)
+                        } else {
+                            write!(f, " {}", index)
+                        }
+                    })?;
+                    write!(f, ")")
+                })
+            }
+            Notation::TwoLine => {
+                let width = num_digits(indices.len().saturating_sub(1));
+                write_row(f, 0..indices.len(), width)?;
+                writeln!(f)?;
+                write_row(f, indices.iter().copied(), width)
+            }
+        }
+    }
+}
+
+/// An error which can be returned when parsing a permutation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsePermError {
+    position: usize,
+    kind: ParsePermErrorKind,
+}
+
+impl ParsePermError {
+    /// Gets the byte offset of the error in the text.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets the reason of the error.
+    pub fn kind(&self) -> &ParsePermErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParsePermError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Self { position, kind } = self;
+        match kind {
+            ParsePermErrorKind::UnexpectedChar(ch) => {
+                write!(f, "unexpected character {:?} at position {}", ch, position)
+            }
+            ParsePermErrorKind::UnexpectedEnd => {
+                write!(f, "unexpected end of text at position {}", position)
+            }
+            ParsePermErrorKind::NumberOverflow => {
+                write!(f, "number too large at position {}", position)
+            }
+            ParsePermErrorKind::IndexOutOfRange => {
+                write!(f, "index out of range at position {}", position)
+            }
+            ParsePermErrorKind::DuplicateIndex => {
+                write!(f, "duplicated index at position {}", position)
+            }
+            ParsePermErrorKind::LengthMismatch => {
+                write!(f, "permutation length mismatch at position {}", position)
+            }
+            ParsePermErrorKind::InvalidTopRow => {
+                write!(f, "top row is not in order at position {}", position)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePermError {}
+
+/// The reason of a [ParsePermError].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParsePermErrorKind {
+    /// An unexpected character is found.
+    UnexpectedChar(char),
+    /// The text ends unexpectedly.
+    UnexpectedEnd,
+    /// A number does not fit in `usize`.
+    NumberOverflow,
+    /// An index is not less than the permutation size.
+    IndexOutOfRange,
+    /// An index appears more than once.
+    DuplicateIndex,
+    /// The number of indices does not match the permutation size.
+    LengthMismatch,
+    /// The top row of two-line notation is not `0 1 .. n-1`.
+    InvalidTopRow,
+}
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> Display for PermS<SIZE> {
+        /// Formats the permutation in one-line notation.
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            self.display(Notation::OneLine).fmt(f)
+        }
+    }
+
+    impl<const SIZE: usize> FromStr for PermS<SIZE> {
+        type Err = ParsePermError;
+
+        /// Parses a static permutation in one-line, two-line or cycle notation.
+        fn from_str(text: &str) -> Result<Self, Self::Err> {
+            let mut perm = Self::identity();
+            parse(text, &mut perm.indices, &mut [false; SIZE])?;
+            Ok(perm)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::from_indices::PermFromIndices;
+
+        #[test]
+        fn static_parse() {
+            let perm = PermS::from_indices([2, 0, 1, 4, 3]).unwrap();
+
+            assert_eq!("[2 0 1 4 3]".parse::<PermS<5>>().unwrap(), perm);
+            assert_eq!(" [ 2, 0, 1 ,4,3 ] ".parse::<PermS<5>>().unwrap(), perm);
+            assert_eq!("(0 2 1)(3 4)".parse::<PermS<5>>().unwrap(), perm);
+            assert_eq!(" ( 3, 4 ) (1,0 ,2)".parse::<PermS<5>>().unwrap(), perm);
+            assert_eq!(
+                "[0 1 2 3 4]\n[2 0 1 4 3]".parse::<PermS<5>>().unwrap(),
+                perm
+            );
+            assert_eq!("()".parse::<PermS<5>>().unwrap(), PermS::<5>::identity());
+            assert_eq!("[]".parse::<PermS<0>>().unwrap(), PermS::<0>::identity());
+
+            let error = |text: &str| {
+                let error = text.parse::<PermS<5>>().unwrap_err();
+                (error.position(), error.kind().clone())
+            };
+            assert_eq!(error(""), (0, ParsePermErrorKind::UnexpectedEnd));
+            assert_eq!(error("[2 0 1 4"), (8, ParsePermErrorKind::UnexpectedEnd));
+            assert_eq!(error("[2 0 1 4]"), (8, ParsePermErrorKind::LengthMismatch));
+            assert_eq!(
+                error("[2 0 1 4 3 5]"),
+                (11, ParsePermErrorKind::LengthMismatch)
+            );
+            assert_eq!(
+                error("[2 0 1 5 3]"),
+                (7, ParsePermErrorKind::IndexOutOfRange)
+            );
+            assert_eq!(
+                error("[2 0 1 2 3]"),
+                (7, ParsePermErrorKind::DuplicateIndex)
+            );
+            assert_eq!(error("(0 1)(1 2)"), (6, ParsePermErrorKind::DuplicateIndex));
+            assert_eq!(error("(0 x)"), (3, ParsePermErrorKind::UnexpectedChar('x')));
+            assert_eq!(
+                error("[2 0 1 4 3] x"),
+                (12, ParsePermErrorKind::UnexpectedChar('x'))
+            );
+            assert_eq!(
+                error("[0 1 2 4 3]\n[2 0 1 4 3]"),
+                (7, ParsePermErrorKind::InvalidTopRow)
+            );
+            assert_eq!(
+                error("[99999999999999999999999 1]"),
+                (1, ParsePermErrorKind::NumberOverflow)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::perm_type::PermD;
+
+    impl Display for PermD {
+        /// Formats the permutation in one-line notation.
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            self.display(Notation::OneLine).fmt(f)
+        }
+    }
+
+    impl FromStr for PermD {
+        type Err = ParsePermError;
+
+        /// Parses a dynamic permutation in one-line, two-line or cycle notation.
+        ///
+        /// The cycle notation must list every index including fixed points,
+        /// and the size is the number of listed indices.
+        fn from_str(text: &str) -> Result<Self, Self::Err> {
+            let mut perm = parse_identity(text)?;
+            let len = perm.indices.len();
+            parse(text, &mut perm.indices, &mut vec![false; len])?;
+            Ok(perm)
+        }
+    }
+
+    /// Builds the identity sized from text without validating the indices.
+    fn parse_identity(text: &str) -> Result<PermD, ParsePermError> {
+        let mut parser = Parser::new(text);
+
+        let size = match parser.peek()? {
+            (_, '(') => {
+                let mut count = 0;
+                parser.cycles(|_, event| {
+                    if let CycleEvent::Index(_) | CycleEvent::Start(_) = event {
+                        count += 1;
+                    }
+                    Ok(())
+                })?;
+                count
+            }
+            _ => parser.row(|_, _, _| Ok(()))?,
+        };
+        Ok(PermD::identity(size))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{from_indices::PermFromIndices, perm_type::PermS};
+        use rand::prelude::*;
+
+        #[test]
+        fn dynamic_display() {
+            let perm = PermD::from_indices([2, 0, 1, 4, 3, 5]).unwrap();
+            assert_eq!(perm.to_string(), "[2 0 1 4 3 5]");
+            assert_eq!(perm.display(Notation::Cycle).to_string(), "(0 2 1)(3 4)(5)");
+            assert_eq!(
+                perm.display(Notation::TwoLine).to_string(),
+                "[0 1 2 3 4 5]\n[2 0 1 4 3 5]"
+            );
+            assert_eq!(
+                PermD::identity(3).display(Notation::Cycle).to_string(),
+                "(0)(1)(2)"
+            );
+            assert_eq!(PermD::empty().display(Notation::Cycle).to_string(), "()");
+            assert_eq!(PermD::empty().to_string(), "[]");
+
+            let perm = PermS::<11>::cycle();
+            assert_eq!(
+                perm.display(Notation::TwoLine).to_string(),
+                "[ 0  1  2  3  4  5  6  7  8  9 10]\n[10  0  1  2  3  4  5  6  7  8  9]"
+            );
+        }
+
+        #[test]
+        fn dynamic_parse() {
+            assert_eq!(
+                "(0 2 1)(3 4)".parse::<PermD>().unwrap(),
+                PermD::from_indices([2, 0, 1, 4, 3]).unwrap()
+            );
+            assert_eq!("()".parse::<PermD>().unwrap(), PermD::empty());
+            assert_eq!("[]".parse::<PermD>().unwrap(), PermD::empty());
+            assert_eq!(
+                "[3 0 1]".parse::<PermD>().unwrap_err().kind(),
+                &ParsePermErrorKind::IndexOutOfRange
+            );
+            assert_eq!(
+                "[0 1 2]\n[1 0]".parse::<PermD>().unwrap_err().kind(),
+                &ParsePermErrorKind::LengthMismatch
+            );
+
+            // the size is the number of indices, so omitted fixed points are out of range
+            let error = "(0 1)(3)".parse::<PermD>().unwrap_err();
+            assert_eq!(
+                (error.position(), error.kind()),
+                (6, &ParsePermErrorKind::IndexOutOfRange)
+            );
+            let error = "(0 200000000)".parse::<PermD>().unwrap_err();
+            assert_eq!(
+                (error.position(), error.kind()),
+                (3, &ParsePermErrorKind::IndexOutOfRange)
+            );
+            let error = "(0 18446744073709551615)".parse::<PermD>().unwrap_err();
+            assert_eq!(
+                (error.position(), error.kind()),
+                (3, &ParsePermErrorKind::IndexOutOfRange)
+            );
+            let error = "(1 0)(9223372036854775807)".parse::<PermD>().unwrap_err();
+            assert_eq!(
+                (error.position(), error.kind()),
+                (6, &ParsePermErrorKind::IndexOutOfRange)
+            );
+
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(100);
+            perm.indices.shuffle(&mut rng);
+
+            [
+                perm,
+                PermD::swap(5, 0, 1).unwrap(),
+                PermD::identity(3),
+                PermD::empty(),
+            ]
+            .iter()
+            .for_each(|perm| {
+                [Notation::OneLine, Notation::Cycle, Notation::TwoLine]
+                    .iter()
+                    .for_each(|&notation| {
+                        let text = perm.display(notation).to_string();
+                        let parsed: PermD = text.parse().unwrap();
+                        assert_eq!(&parsed, perm);
+                    });
+            });
+        }
+    }
+}
+
+fn write_row<I>(f: &mut Formatter<'_>, values: I, width: usize) -> fmt::Result
+where
+    I: IntoIterator<Item = usize>,
+{
+    write!(f, "[")?;
+    values
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(nth, value)| {
+            if nth == 0 {
+                write!(f, "{:>width$}", value, width = width)
+            } else {
+                write!(f, " {:>width$}", value, width = width)
+            }
+        })?;
+    write!(f, "]")
+}
+
+fn num_digits(mut value: usize) -> usize {
+    let mut len = 1;
+    while value >= 10 {
+        value /= 10;
+        len += 1;
+    }
+    len
+}
+
+/// Parses the text into identity indices.
+fn parse(text: &str, indices: &mut [usize], visited: &mut [bool]) -> Result<(), ParsePermError> {
+    let mut parser = Parser::new(text);
+
+    match parser.peek()? {
+        (_, '(') => {
+            let mut first = 0;
+            let mut prev = 0;
+
+            parser.cycles(|position, event| {
+                match event {
+                    CycleEvent::Start(index) => {
+                        check_index(position, index, visited)?;
+                        first = index;
+                        prev = index;
+                    }
+                    CycleEvent::Index(index) => {
+                        check_index(position, index, visited)?;
+                        indices[prev] = index;
+                        prev = index;
+                    }
+                    CycleEvent::End => {
+                        indices[prev] = first;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+        _ => {
+            read_row(&mut parser, indices, visited)?;
+
+            // two-line notation, where the top row must be 0..n in order
+            if let Some((_, '[')) = parser.peek_end() {
+                Parser::new(text).row(|position, nth, index| {
+                    if index != nth {
+                        return Err(ParsePermError {
+                            position,
+                            kind: ParsePermErrorKind::InvalidTopRow,
+                        });
+                    }
+                    Ok(())
+                })?;
+
+                visited.iter_mut().for_each(|flag| *flag = false);
+                read_row(&mut parser, indices, visited)?;
+            }
+        }
+    }
+
+    parser.end()
+}
+
+fn read_row(
+    parser: &mut Parser<'_>,
+    indices: &mut [usize],
+    visited: &mut [bool],
+) -> Result<(), ParsePermError> {
+    let len = indices.len();
+
+    let row_len = parser.row(|position, nth, index| {
+        if nth >= len {
+            return Err(ParsePermError {
+                position,
+                kind: ParsePermErrorKind::LengthMismatch,
+            });
+        }
+        check_index(position, index, visited)?;
+        indices[nth] = index;
+        Ok(())
+    })?;
+
+    if row_len != len {
+        return Err(ParsePermError {
+            position: parser.position - 1,
+            kind: ParsePermErrorKind::LengthMismatch,
+        });
+    }
+    Ok(())
+}
+
+fn check_index(position: usize, index: usize, visited: &mut [bool]) -> Result<(), ParsePermError> {
+    let kind = if index >= visited.len() {
+        ParsePermErrorKind::IndexOutOfRange
+    } else if visited[index] {
+        ParsePermErrorKind::DuplicateIndex
+    } else {
+        visited[index] = true;
+        return Ok(());
+    };
+    Err(ParsePermError { position, kind })
+}
+
+enum CycleEvent {
+    Start(usize),
+    Index(usize),
+    End,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Peeks the next non-whitespace character.
+    fn peek(&mut self) -> Result<(usize, char), ParsePermError> {
+        self.skip_whitespace();
+        match self.text[self.position..].chars().next() {
+            Some(ch) => Ok((self.position, ch)),
+            None => Err(ParsePermError {
+                position: self.position,
+                kind: ParsePermErrorKind::UnexpectedEnd,
+            }),
+        }
+    }
+
+    /// Peeks the next non-whitespace character, or returns `None` at the end of text.
+    fn peek_end(&mut self) -> Option<(usize, char)> {
+        self.peek().ok()
+    }
+
+    fn unexpected(&mut self) -> ParsePermError {
+        match self.peek() {
+            Ok((position, ch)) => ParsePermError {
+                position,
+                kind: ParsePermErrorKind::UnexpectedChar(ch),
+            },
+            Err(error) => error,
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParsePermError> {
+        match self.peek()? {
+            (_, ch) if ch == expected => {
+                self.position += ch.len_utf8();
+                Ok(())
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn end(&mut self) -> Result<(), ParsePermError> {
+        match self.peek_end() {
+            Some(_) => Err(self.unexpected()),
+            None => Ok(()),
+        }
+    }
+
+    /// Reads a number if the next character is a digit.
+    fn number(&mut self) -> Result<Option<(usize, usize)>, ParsePermError> {
+        let (position, _) = self.peek()?;
+        let rest = &self.text[position..];
+        let len = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Ok(None);
+        }
+
+        let value = rest[..len].parse().map_err(|_| ParsePermError {
+            position,
+            kind: ParsePermErrorKind::NumberOverflow,
+        })?;
+        self.position += len;
+        Ok(Some((position, value)))
+    }
+
+    /// Reads numbers separated by whitespaces or an optional comma until the closing bracket.
+    fn list<F>(&mut self, close: char, mut f: F) -> Result<usize, ParsePermError>
+    where
+        F: FnMut(usize, usize, usize) -> Result<(), ParsePermError>,
+    {
+        let mut count = 0;
+
+        loop {
+            if count > 0 {
+                if self.peek()?.1 == close {
+                    break;
+                }
+                if self.peek()?.1 == ',' {
+                    self.position += 1;
+                }
+            }
+            match self.number()? {
+                Some((position, value)) => {
+                    f(position, count, value)?;
+                    count += 1;
+                }
+                None if count == 0 => break,
+                None => return Err(self.unexpected()),
+            }
+        }
+
+        self.expect(close)?;
+        Ok(count)
+    }
+
+    /// Reads a row in brackets, and returns the number of indices.
+    fn row<F>(&mut self, f: F) -> Result<usize, ParsePermError>
+    where
+        F: FnMut(usize, usize, usize) -> Result<(), ParsePermError>,
+    {
+        self.expect('[')?;
+        self.list(']', f)
+    }
+
+    /// Reads a sequence of cycles in parentheses.
+    fn cycles<F>(&mut self, mut f: F) -> Result<(), ParsePermError>
+    where
+        F: FnMut(usize, CycleEvent) -> Result<(), ParsePermError>,
+    {
+        self.expect('(')?;
+
+        loop {
+            let count = self.list(')', |position, nth, index| {
+                if nth == 0 {
+                    f(position, CycleEvent::Start(index))
+                } else {
+                    f(position, CycleEvent::Index(index))
+                }
+            })?;
+            if count > 0 {
+                f(self.position - 1, CycleEvent::End)?;
+            }
+
+            match self.peek_end() {
+                Some((_, '(')) => self.position += 1,
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,18 @@
+use crate::{cycles::Cycles, perm_type::Perm, size::PermSize};
+
 /// An abstract representation of permutation data structure.
 pub trait Permutation {
+    /// The buffer of visited flags used to walk the cycles.
+    type Visited: AsMut<[bool]>;
+
     /// Gets the size of permutation.
     fn len(&self) -> usize;
 
+    /// Checks if the permutation has size zero.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Builds the inverse of permutation.
     fn inverse(&self) -> Self;
 
@@ -10,6 +20,88 @@ pub trait Permutation {
     fn indices(&self) -> &[usize];
 
     fn pow(&self, exp: u32) -> Self;
+
+    /// Iterates over the disjoint cycles of permutation.
+    fn cycles(&self) -> Cycles<'_, Self::Visited>;
+
+    /// Gets the parity of permutation.
+    fn parity(&self) -> Parity {
+        // each cycle of length k is a product of (k - 1) transpositions
+        let num_cycles = self.cycles().count();
+        match (self.len() - num_cycles) % 2 {
+            0 => Parity::Even,
+            _ => Parity::Odd,
+        }
+    }
+
+    /// Gets the sign of permutation, which is 1 for even and -1 for odd permutations.
+    fn sign(&self) -> i8 {
+        self.parity().sign()
+    }
+
+    /// Checks if the permutation is even.
+    fn is_even(&self) -> bool {
+        self.parity() == Parity::Even
+    }
+
+    /// Checks if the permutation is odd.
+    fn is_odd(&self) -> bool {
+        self.parity() == Parity::Odd
+    }
+
+    /// Gets the order of permutation, the least common multiple of the cycle lengths.
+    ///
+    /// It returns `None` if the order overflows `u128`.
+    fn order(&self) -> Option<u128> {
+        self.cycles()
+            .skip_fixed_points()
+            .try_fold(1u128, |order, cycle| lcm(order, cycle.len() as u128))
+    }
+}
+
+impl<S> Perm<S>
+where
+    S: PermSize,
+    S::Container: Clone,
+    Self: Permutation,
+{
+    /// Raises the permutation to a signed power, where a negative exponent raises the inverse.
+    ///
+    /// The exponent is reduced modulo each cycle length, which takes O(n) time for any exponent.
+    pub fn pow_i128(&self, exp: i128) -> Self {
+        let mut pow = Self {
+            indices: self.scratch(),
+        };
+        signed_pow(
+            self.indices.as_ref(),
+            self.cycles(),
+            pow.indices.as_mut(),
+            exp,
+        );
+        pow
+    }
+
+    /// Raises the permutation to a signed `i64` power, where a negative exponent raises the inverse.
+    pub fn pow_i64(&self, exp: i64) -> Self {
+        self.pow_i128(exp as i128)
+    }
+}
+
+/// The parity of a permutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Parity {
+    Even,
+    Odd,
+}
+
+impl Parity {
+    /// Gets the sign, which is 1 for even and -1 for odd parity.
+    pub fn sign(&self) -> i8 {
+        match self {
+            Self::Even => 1,
+            Self::Odd => -1,
+        }
+    }
 }
 
 mod without_std {
@@ -17,6 +109,8 @@ mod without_std {
     use crate::perm_type::PermS;
 
     impl<const SIZE: usize> Permutation for PermS<SIZE> {
+        type Visited = [bool; SIZE];
+
         fn indices(&self) -> &[usize] {
             self.indices.as_ref()
         }
@@ -52,15 +146,20 @@ mod without_std {
 
             pow
         }
+
+        fn cycles(&self) -> Cycles<'_, Self::Visited> {
+            Cycles::new(&self.indices, [false; SIZE])
+        }
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
-        use crate::apply::PermApply;
+        use crate::{apply::PermApply, from_indices::PermFromIndices};
         use rand::prelude::*;
 
         #[test]
+        #[allow(clippy::clone_on_copy)]
         fn static_inverse() {
             const SIZE: usize = 1024;
             {
@@ -87,15 +186,52 @@ mod without_std {
                 );
             }
         }
+
+        #[test]
+        fn static_parity_and_order() {
+            assert_eq!(PermS::<0>::identity().sign(), 1);
+            assert_eq!(PermS::<5>::identity().order(), Some(1));
+            assert_eq!(PermS::<5>::swap(1, 3).unwrap().parity(), Parity::Odd);
+            assert_eq!(PermS::<5>::cycle().parity(), Parity::Even);
+            assert_eq!(PermS::<6>::cycle().sign(), -1);
+
+            // (0 1)(2 3 4)(5 6 7 8)
+            let perm = PermS::from_indices([1, 0, 3, 4, 2, 6, 7, 8, 5]).unwrap();
+            assert!(perm.is_even());
+            assert_eq!(perm.order(), Some(12));
+            assert_eq!(perm.pow(12), PermS::<9>::identity());
+            assert_ne!(perm.pow(6), PermS::<9>::identity());
+            assert_ne!(perm.pow(4), PermS::<9>::identity());
+        }
+
+        #[test]
+        fn static_signed_pow() {
+            // (0 1)(2 3 4)(5 6 7 8)
+            let perm = PermS::from_indices([1, 0, 3, 4, 2, 6, 7, 8, 5]).unwrap();
+            (0..30).for_each(|exp| {
+                assert_eq!(perm.pow_i64(exp), perm.pow(exp as u32));
+                assert_eq!(perm.pow_i64(-exp), perm.inverse().pow(exp as u32));
+            });
+
+            assert_eq!(perm.pow_i128(i128::MAX), perm.pow((i128::MAX % 12) as u32));
+            assert_eq!(
+                perm.pow_i128(i128::MIN),
+                perm.pow(i128::MIN.rem_euclid(12) as u32)
+            );
+            assert_eq!(perm.pow_i64(-1), perm.inverse());
+            assert_eq!(PermS::<0>::identity().pow_i64(-5), PermS::<0>::identity());
+        }
     }
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
-    use crate::perm_type::PermD;
+    use crate::{common::*, perm_type::PermD};
 
     impl Permutation for PermD {
+        type Visited = Vec<bool>;
+
         fn indices(&self) -> &[usize] {
             self.indices.as_ref()
         }
@@ -131,6 +267,10 @@ mod with_std {
 
             pow
         }
+
+        fn cycles(&self) -> Cycles<'_, Self::Visited> {
+            Cycles::new(&self.indices, vec![false; self.indices.len()])
+        }
     }
 
     #[cfg(test)]
@@ -141,6 +281,7 @@ mod with_std {
         use std::collections::HashSet;
 
         #[test]
+        #[allow(clippy::clone_on_copy)]
         fn dynamic_inverse() {
             const SIZE: usize = 1024;
             {
@@ -165,6 +306,46 @@ mod with_std {
             }
         }
 
+        #[test]
+        fn dynamic_order() {
+            let mut rng = rand::thread_rng();
+
+            for _ in 0..100 {
+                let mut perm = PermD::identity(64);
+                perm.indices.shuffle(&mut rng);
+
+                let order = perm.order().unwrap();
+                let identity = PermD::identity(64);
+                assert_eq!(perm.pow(order as u32), identity);
+                assert_eq!(perm.pow(order as u32 + 1), perm);
+
+                let sign = perm.sign();
+                assert_eq!((&perm * &perm).sign(), 1);
+                assert_eq!((&perm * &PermD::swap(64, 0, 1).unwrap()).sign(), -sign);
+            }
+
+            // product of cycles of distinct prime lengths overflows u128
+            let primes = [
+                2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+                83, 89, 97, 101, 103, 107,
+            ];
+            let mut indices = vec![];
+            primes.iter().for_each(|&len| {
+                let offset = indices.len();
+                indices.extend((1..len).map(|index| index + offset));
+                indices.push(offset);
+            });
+            let perm = PermD { indices };
+            assert_eq!(perm.order(), None);
+            assert_eq!(
+                PermD {
+                    indices: perm.indices[..2].to_vec()
+                }
+                .order(),
+                Some(2)
+            );
+        }
+
         #[test]
         fn dynamic_pow() {
             let cycle = PermD::cycle(6);
@@ -173,6 +354,20 @@ mod with_std {
 
             let set: HashSet<_> = (0..6).map(|exp| cycle.pow(exp)).collect();
             assert_eq!(set.len(), 6);
+
+            let mut rng = rand::thread_rng();
+            let mut perm = PermD::identity(256);
+            perm.indices.shuffle(&mut rng);
+            let exp: i64 = rng.gen_range(-1000..1000);
+            assert_eq!(
+                perm.pow_i64(exp),
+                match exp < 0 {
+                    true => perm.inverse().pow(exp.unsigned_abs() as u32),
+                    false => perm.pow(exp as u32),
+                }
+            );
+            assert_eq!(cycle.pow_i128(-(6 << 100) - 1), PermD::reverse_cycle(6));
+            assert_eq!(cycle.pow_i64(i64::MIN), cycle.pow_i64(i64::MIN % 6));
         }
 
         #[test]
@@ -193,6 +388,41 @@ fn inverse_indices(indices: &[usize], inverse_indices: &mut [usize]) {
     });
 }
 
+/// Writes the power of indices by shifting along each cycle by the exponent modulo the cycle length.
+fn signed_pow<V>(indices: &[usize], cycles: Cycles<'_, V>, pow: &mut [usize], exp: i128)
+where
+    V: AsMut<[bool]>,
+{
+    cycles.for_each(|cycle| {
+        let start = cycle.start();
+        let shift = exp.rem_euclid(cycle.len() as i128) as usize;
+        let target = (0..shift).fold(start, |point, _| indices[point]);
+
+        let (mut from, mut to) = (start, target);
+        loop {
+            pow[from] = to;
+            from = indices[from];
+            to = indices[to];
+            if from == start {
+                break;
+            }
+        }
+    });
+}
+
+pub(crate) fn gcd(mut lhs: u128, mut rhs: u128) -> u128 {
+    while rhs != 0 {
+        let rem = lhs % rhs;
+        lhs = rhs;
+        rhs = rem;
+    }
+    lhs
+}
+
+fn lcm(lhs: u128, rhs: u128) -> Option<u128> {
+    (lhs / gcd(lhs, rhs)).checked_mul(rhs)
+}
+
 fn u32_leading_bit(n: u32) -> u32 {
     let n = n as u64;
     let n = n | (n >> 1);
@@ -1,7 +1,7 @@
-use crate::{common::*, perm_trait::Permutation, size::PermSize};
+use crate::{common::*, error::PermError, perm_trait::Permutation, size::PermSize};
 
-#[cfg(feature = "std")]
-pub use with_std::*;
+#[cfg(feature = "alloc")]
+pub use with_alloc::*;
 pub use without_std::*;
 
 /// Generic permutation data structure.
@@ -23,6 +23,19 @@ where
     }
 }
 
+impl<S> Perm<S>
+where
+    S: PermSize,
+    S::Container: Clone,
+{
+    /// Creates a scratch buffer with the size of the permutation, whose content is unspecified.
+    ///
+    /// It clones the indices since `S::Container` cannot be created generically.
+    pub(crate) fn scratch(&self) -> S::Container {
+        self.indices.clone()
+    }
+}
+
 mod without_std {
     use super::*;
     use crate::size::Static;
@@ -133,10 +146,8 @@ mod without_std {
             Self { indices }
         }
 
-        pub fn swap(first: usize, second: usize) -> Option<Self> {
-            if first >= SIZE || second >= SIZE || first == second {
-                return None;
-            }
+        pub fn swap(first: usize, second: usize) -> Result<Self, PermError> {
+            check_swap(SIZE, first, second)?;
 
             let min = first.min(second);
             let max = first.max(second);
@@ -155,7 +166,7 @@ mod without_std {
                 indices[index] = index;
             });
 
-            Some(Self { indices })
+            Ok(Self { indices })
         }
 
         pub fn cycle() -> Self {
@@ -188,18 +199,21 @@ mod without_std {
             &(&other.inverse() * self) * other
         }
 
-        pub fn to_size<const NEW_SIZE: usize>(&self) -> Option<PermS<NEW_SIZE>> {
+        pub fn to_size<const NEW_SIZE: usize>(&self) -> Result<PermS<NEW_SIZE>, PermError> {
             if SIZE > NEW_SIZE {
                 for dst in NEW_SIZE..SIZE {
                     let src = self.indices[dst];
                     if src != dst {
-                        return None;
+                        return Err(PermError::NonFixedTail {
+                            position: dst,
+                            index: src,
+                        });
                     }
                 }
 
                 let mut new_indices = [0; NEW_SIZE];
                 new_indices.copy_from_slice(&self.indices[..NEW_SIZE]);
-                Some(PermS {
+                Ok(PermS {
                     indices: new_indices,
                 })
             } else {
@@ -210,7 +224,7 @@ mod without_std {
                     new_indices[index] = index;
                 });
 
-                Some(PermS {
+                Ok(PermS {
                     indices: new_indices,
                 })
             }
@@ -242,6 +256,7 @@ mod without_std {
         use rand::prelude::*;
 
         #[test]
+        #[allow(clippy::clone_on_copy)]
         fn static_identity() {
             const SIZE: usize = 2014;
 
@@ -273,10 +288,10 @@ mod without_std {
     }
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
-    use crate::{product::PermProduct, size::Dynamic};
+    use crate::{error::check_len, product::PermProduct, size::Dynamic};
 
     /// Permutation type with runtime size.
     pub type PermD = Perm<Dynamic>;
@@ -290,10 +305,8 @@ mod with_std {
             Self { indices: vec![0] }
         }
 
-        pub fn swap(size: usize, first: usize, second: usize) -> Option<Self> {
-            if first >= size || second >= size || first == second {
-                return None;
-            }
+        pub fn swap(size: usize, first: usize, second: usize) -> Result<Self, PermError> {
+            check_swap(size, first, second)?;
 
             let min = first.min(second);
             let max = first.max(second);
@@ -305,7 +318,7 @@ mod with_std {
                 .chain((max + 1)..size)
                 .collect();
 
-            Some(Self { indices })
+            Ok(Self { indices })
         }
 
         pub fn identity(size: usize) -> Self {
@@ -314,6 +327,14 @@ mod with_std {
             Self { indices }
         }
 
+        /// Builds the identity, or returns `None` if the indices cannot be allocated.
+        pub(crate) fn try_identity(size: usize) -> Option<Self> {
+            let mut indices = Vec::new();
+            indices.try_reserve_exact(size).ok()?;
+            indices.extend(0..size);
+            Some(Self { indices })
+        }
+
         pub fn cycle(size: usize) -> Self {
             let mut indices = vec![0; size];
             iter::once(size - 1)
@@ -336,27 +357,30 @@ mod with_std {
             Self { indices }
         }
 
-        pub fn permute_indices(&self, perm: &PermD) -> Option<Self> {
+        pub fn permute_indices(&self, perm: &PermD) -> Result<Self, PermError> {
             self.conjugate_with(perm)
         }
 
-        pub fn conjugate_with(&self, other: &PermD) -> Option<Self> {
+        pub fn conjugate_with(&self, other: &PermD) -> Result<Self, PermError> {
             other.inverse().perm_product(self)?.perm_product(other)
         }
 
-        pub fn to_size(&self, new_size: usize) -> Option<PermD> {
+        pub fn to_size(&self, new_size: usize) -> Result<PermD, PermError> {
             let orig_size = self.indices.len();
             if orig_size > new_size {
                 for dst in new_size..orig_size {
                     let src = self.indices[dst];
                     if src != dst {
-                        return None;
+                        return Err(PermError::NonFixedTail {
+                            position: dst,
+                            index: src,
+                        });
                     }
                 }
 
                 let mut new_indices = vec![0; new_size];
                 new_indices.copy_from_slice(&self.indices[..new_size]);
-                Some(PermD {
+                Ok(PermD {
                     indices: new_indices,
                 })
             } else {
@@ -367,16 +391,17 @@ mod with_std {
                     new_indices[index] = index;
                 });
 
-                Some(PermD {
+                Ok(PermD {
                     indices: new_indices,
                 })
             }
         }
 
-        pub fn into_static<const SIZE: usize>(self) -> Option<PermS<SIZE>> {
+        pub fn into_static<const SIZE: usize>(self) -> Result<PermS<SIZE>, PermError> {
             let Self { indices } = self;
-            let indices = <[usize; SIZE]>::try_from(indices).ok()?;
-            Some(PermS { indices })
+            check_len(SIZE, indices.len())?;
+            let indices = <[usize; SIZE]>::try_from(indices).unwrap();
+            Ok(PermS { indices })
         }
     }
 
@@ -396,6 +421,7 @@ mod with_std {
         use rand::prelude::*;
 
         #[test]
+        #[allow(clippy::clone_on_copy)]
         fn dynamic_identity() {
             const SIZE: usize = 2014;
 
@@ -415,6 +441,43 @@ mod with_std {
         fn dynamic_swap() {
             let perm = PermD::swap(6, 5, 3).unwrap();
             assert_eq!(perm.indices(), &[0, 1, 2, 5, 4, 3]);
+
+            assert_eq!(
+                PermD::swap(6, 2, 2),
+                Err(PermError::IdenticalIndices { index: 2 })
+            );
+            assert_eq!(
+                PermD::swap(6, 2, 6),
+                Err(PermError::IndexOutOfRange {
+                    position: 1,
+                    index: 6,
+                    len: 6
+                })
+            );
+        }
+
+        #[test]
+        fn dynamic_to_size() {
+            let perm = PermD::swap(6, 1, 3).unwrap();
+            assert_eq!(perm.to_size(4).unwrap(), PermD::swap(4, 1, 3).unwrap());
+            assert_eq!(
+                perm.to_size(3),
+                Err(PermError::NonFixedTail {
+                    position: 3,
+                    index: 1
+                })
+            );
+            assert_eq!(
+                perm.clone().into_static::<5>(),
+                Err(PermError::LengthMismatch {
+                    expected: 5,
+                    actual: 6
+                })
+            );
+            assert_eq!(
+                perm.into_static::<6>().unwrap(),
+                PermS::<6>::swap(1, 3).unwrap()
+            );
         }
 
         #[test]
@@ -426,3 +489,23 @@ mod with_std {
         }
     }
 }
+
+fn check_swap(size: usize, first: usize, second: usize) -> Result<(), PermError> {
+    [first, second]
+        .iter()
+        .enumerate()
+        .try_for_each(|(position, &index)| {
+            if index >= size {
+                return Err(PermError::IndexOutOfRange {
+                    position,
+                    index,
+                    len: size,
+                });
+            }
+            Ok(())
+        })?;
+    if first == second {
+        return Err(PermError::IdenticalIndices { index: first });
+    }
+    Ok(())
+}
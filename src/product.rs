@@ -48,50 +48,45 @@ mod without_std {
     }
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
     use crate::{
+        error::{check_len, PermError},
         perm_trait::Permutation,
         perm_type::{PermD, PermS},
     };
 
     impl<const SIZE: usize> PermProduct<PermD> for PermS<SIZE> {
-        type Output = Option<PermS<SIZE>>;
+        type Output = Result<PermS<SIZE>, PermError>;
 
         fn perm_product(&self, other: &PermD) -> Self::Output {
-            if other.len() != SIZE {
-                return None;
-            }
+            check_len(SIZE, other.len())?;
             let mut indices = [0; SIZE];
             product(&self.indices, &other.indices, &mut indices);
-            Some(Self { indices })
+            Ok(Self { indices })
         }
     }
 
     impl<const SIZE: usize> PermProduct<PermS<SIZE>> for PermD {
-        type Output = Option<PermS<SIZE>>;
+        type Output = Result<PermS<SIZE>, PermError>;
 
         fn perm_product(&self, other: &PermS<SIZE>) -> Self::Output {
-            if self.len() != SIZE {
-                return None;
-            }
+            check_len(self.len(), SIZE)?;
             let mut indices = [0; SIZE];
             product(&self.indices, &other.indices, &mut indices);
-            Some(PermS { indices })
+            Ok(PermS { indices })
         }
     }
 
     impl PermProduct<PermD> for PermD {
-        type Output = Option<PermD>;
+        type Output = Result<PermD, PermError>;
 
         fn perm_product(&self, other: &PermD) -> Self::Output {
-            if self.len() != other.len() {
-                return None;
-            }
+            check_len(self.len(), other.len())?;
             let mut indices = vec![0; self.len()];
             product(&self.indices, &other.indices, &mut indices);
-            Some(Self { indices })
+            Ok(Self { indices })
         }
     }
 
@@ -119,25 +114,27 @@ mod with_std {
         }
     }
 
-    impl<'a, const SIZE: usize> Product<&'a PermD> for Option<PermS<SIZE>> {
+    impl<'a, const SIZE: usize> Product<&'a PermD> for Result<PermS<SIZE>, PermError> {
         fn product<I>(mut iter: I) -> Self
         where
             I: Iterator<Item = &'a PermD>,
         {
-            iter.try_fold(PermS::<SIZE>::identity(), |product, item| -> Option<_> {
-                product.perm_product(item)
-            })
+            iter.try_fold(
+                PermS::<SIZE>::identity(),
+                |product, item| -> Result<_, PermError> { product.perm_product(item) },
+            )
         }
     }
 
-    impl<const SIZE: usize> Product<PermD> for Option<PermS<SIZE>> {
+    impl<const SIZE: usize> Product<PermD> for Result<PermS<SIZE>, PermError> {
         fn product<I>(mut iter: I) -> Self
         where
             I: Iterator<Item = PermD>,
         {
-            iter.try_fold(PermS::<SIZE>::identity(), |product, item| -> Option<_> {
-                product.perm_product(&item)
-            })
+            iter.try_fold(
+                PermS::<SIZE>::identity(),
+                |product, item| -> Result<_, PermError> { product.perm_product(&item) },
+            )
         }
     }
 
@@ -163,25 +160,25 @@ mod with_std {
         }
     }
 
-    impl<'a> Product<&'a PermD> for Option<PermD> {
+    impl<'a> Product<&'a PermD> for Result<PermD, PermError> {
         fn product<I>(mut iter: I) -> Self
         where
             I: Iterator<Item = &'a PermD>,
         {
-            let first = iter.next()?.to_owned();
-            iter.try_fold(first, |product, item| -> Option<_> {
+            let first = iter.next().ok_or(PermError::EmptyProduct)?.clone();
+            iter.try_fold(first, |product, item| -> Result<_, PermError> {
                 product.perm_product(item)
             })
         }
     }
 
-    impl Product<PermD> for Option<PermD> {
+    impl Product<PermD> for Result<PermD, PermError> {
         fn product<I>(mut iter: I) -> Self
         where
             I: Iterator<Item = PermD>,
         {
-            let first = iter.next()?;
-            iter.try_fold(first, |product, item| -> Option<_> {
+            let first = iter.next().ok_or(PermError::EmptyProduct)?;
+            iter.try_fold(first, |product, item| -> Result<_, PermError> {
                 product.perm_product(&item)
             })
         }
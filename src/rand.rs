@@ -1,16 +1,669 @@
 #![cfg(feature = "rand")]
 
-use crate::perm_type::PermS;
+use crate::{cycles::Cycles, error::PermError, size::PermSize};
+use core::marker::PhantomData;
 use rand::{
     distributions::{Distribution, Standard},
     prelude::*,
 };
 
-impl<const SIZE: usize> Distribution<PermS<SIZE>> for Standard {
-    /// Sample an random static permutation.
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
-        let mut perm = PermS::<SIZE>::identity();
-        perm.indices.shuffle(rng);
-        perm
+#[cfg(feature = "alloc")]
+pub use with_alloc::*;
+
+/// The uniform distribution over derangements, the permutations without fixed points.
+///
+/// The distributions of constrained permutations are parameterized by the size marker.
+/// Those created by `new_static()` sample `PermS`, while those created by `new()` sample `PermD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Derangement<S>
+where
+    S: PermSize,
+{
+    size: usize,
+    _size: PhantomData<S>,
+}
+
+impl<S> Derangement<S>
+where
+    S: PermSize,
+{
+    /// Creates the distribution, which fails if `size` is 1.
+    fn with_size(size: usize) -> Result<Self, PermError> {
+        if size == 1 {
+            return Err(PermError::NoPermutation { len: size });
+        }
+        Ok(Self {
+            size,
+            _size: PhantomData,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// The uniform distribution over involutions, the permutations that are inverses of themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Involution<S>
+where
+    S: PermSize,
+{
+    size: usize,
+    _size: PhantomData<S>,
+}
+
+impl<S> Involution<S>
+where
+    S: PermSize,
+{
+    fn with_size(size: usize) -> Self {
+        Self {
+            size,
+            _size: PhantomData,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// The uniform distribution over cyclic permutations that consist of a single cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CyclicPerm<S>
+where
+    S: PermSize,
+{
+    size: usize,
+    _size: PhantomData<S>,
+}
+
+impl<S> CyclicPerm<S>
+where
+    S: PermSize,
+{
+    fn with_size(size: usize) -> Self {
+        Self {
+            size,
+            _size: PhantomData,
+        }
     }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// The uniform distribution over even permutations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EvenPerm<S>
+where
+    S: PermSize,
+{
+    size: usize,
+    _size: PhantomData<S>,
+}
+
+impl<S> EvenPerm<S>
+where
+    S: PermSize,
+{
+    fn with_size(size: usize) -> Self {
+        Self {
+            size,
+            _size: PhantomData,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// The uniform distribution over permutations of a given cycle type.
+///
+/// The permutation size is the sum of cycle lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CycleTypePerm<C, S>
+where
+    C: AsRef<[usize]>,
+    S: PermSize,
+{
+    size: usize,
+    cycle_type: C,
+    _size: PhantomData<S>,
+}
+
+impl<C, S> CycleTypePerm<C, S>
+where
+    C: AsRef<[usize]>,
+    S: PermSize,
+{
+    /// Creates the distribution from cycle lengths in any order, where zero lengths are ignored.
+    fn with_cycle_type(cycle_type: C) -> Self {
+        Self {
+            size: cycle_type.as_ref().iter().sum(),
+            cycle_type,
+            _size: PhantomData,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn cycle_type(&self) -> &[usize] {
+        self.cycle_type.as_ref()
+    }
+}
+
+mod without_std {
+    use super::*;
+    use crate::{error::check_len, perm_type::PermS, size::Static};
+
+    impl<const SIZE: usize> Distribution<PermS<SIZE>> for Standard {
+        /// Sample an random static permutation.
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
+            let mut perm = PermS::<SIZE>::identity();
+            perm.indices.shuffle(rng);
+            perm
+        }
+    }
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Shuffles the permutation in-place, resulting in a uniformly random permutation.
+        pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+            self.indices.shuffle(rng);
+        }
+    }
+
+    impl<const SIZE: usize> Derangement<Static<SIZE>> {
+        /// Creates the distribution of static permutations, which fails if `SIZE` is 1.
+        pub fn new_static() -> Result<Self, PermError> {
+            Self::with_size(SIZE)
+        }
+    }
+
+    impl<const SIZE: usize> Involution<Static<SIZE>> {
+        /// Creates the distribution of static permutations.
+        pub fn new_static() -> Self {
+            Self::with_size(SIZE)
+        }
+    }
+
+    impl<const SIZE: usize> CyclicPerm<Static<SIZE>> {
+        /// Creates the distribution of static permutations.
+        pub fn new_static() -> Self {
+            Self::with_size(SIZE)
+        }
+    }
+
+    impl<const SIZE: usize> EvenPerm<Static<SIZE>> {
+        /// Creates the distribution of static permutations.
+        pub fn new_static() -> Self {
+            Self::with_size(SIZE)
+        }
+    }
+
+    impl<C, const SIZE: usize> CycleTypePerm<C, Static<SIZE>>
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Creates the distribution of static permutations from cycle lengths in any order,
+        /// which must sum up to `SIZE`.
+        ///
+        /// Zero lengths are ignored, so a zero-padded [cycle type](PermS::cycle_type) is accepted.
+        pub fn new_static(cycle_type: C) -> Result<Self, PermError> {
+            let distribution = Self::with_cycle_type(cycle_type);
+            check_len(SIZE, distribution.size)?;
+            Ok(distribution)
+        }
+    }
+
+    impl<const SIZE: usize> Distribution<PermS<SIZE>> for Derangement<Static<SIZE>> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
+            let mut perm = PermS::<SIZE>::identity();
+            derangement(&mut perm.indices, rng);
+            perm
+        }
+    }
+
+    impl<const SIZE: usize> Distribution<PermS<SIZE>> for Involution<Static<SIZE>> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
+            let mut perm = PermS::<SIZE>::identity();
+            involution(&mut perm.indices, &mut [0; SIZE], &mut [0.0; SIZE], rng);
+            perm
+        }
+    }
+
+    impl<const SIZE: usize> Distribution<PermS<SIZE>> for CyclicPerm<Static<SIZE>> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
+            let mut perm = PermS::<SIZE>::identity();
+            sattolo(&mut perm.indices, rng);
+            perm
+        }
+    }
+
+    impl<const SIZE: usize> Distribution<PermS<SIZE>> for EvenPerm<Static<SIZE>> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
+            let mut perm = PermS::<SIZE>::identity();
+            even(&mut perm.indices, &mut [false; SIZE], rng);
+            perm
+        }
+    }
+
+    impl<C, const SIZE: usize> Distribution<PermS<SIZE>> for CycleTypePerm<C, Static<SIZE>>
+    where
+        C: AsRef<[usize]>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermS<SIZE> {
+            let mut perm = PermS::<SIZE>::identity();
+            with_cycle_type(
+                &mut perm.indices,
+                &mut [0; SIZE],
+                self.cycle_type.as_ref(),
+                rng,
+            );
+            perm
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{
+        common::*, group::PermGroup, perm_trait::Permutation, perm_type::PermD, size::Dynamic,
+    };
+
+    /// The uniform distribution over dynamic permutations of a given size.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct UniformPerm {
+        size: usize,
+    }
+
+    impl UniformPerm {
+        pub fn new(size: usize) -> Self {
+            Self { size }
+        }
+
+        pub fn size(&self) -> usize {
+            self.size
+        }
+
+        /// Samples a random permutation into an existing one, reusing its allocation.
+        pub fn sample_into<R: Rng + ?Sized>(&self, perm: &mut PermD, rng: &mut R) {
+            perm.indices.clear();
+            perm.indices.extend(0..self.size);
+            perm.indices.shuffle(rng);
+        }
+    }
+
+    impl Distribution<PermD> for UniformPerm {
+        /// Sample an random dynamic permutation.
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermD {
+            let mut perm = PermD::identity(self.size);
+            perm.indices.shuffle(rng);
+            perm
+        }
+    }
+
+    impl PermD {
+        /// Samples a uniformly random permutation of a given size.
+        pub fn random<R: Rng + ?Sized>(size: usize, rng: &mut R) -> Self {
+            UniformPerm::new(size).sample(rng)
+        }
+
+        /// Shuffles the permutation in-place, resulting in a uniformly random permutation.
+        pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+            self.indices.shuffle(rng);
+        }
+    }
+
+    impl Derangement<Dynamic> {
+        /// Creates the distribution of dynamic permutations, which fails if `size` is 1.
+        pub fn new(size: usize) -> Result<Self, PermError> {
+            Self::with_size(size)
+        }
+    }
+
+    impl Involution<Dynamic> {
+        /// Creates the distribution of dynamic permutations.
+        pub fn new(size: usize) -> Self {
+            Self::with_size(size)
+        }
+    }
+
+    impl CyclicPerm<Dynamic> {
+        /// Creates the distribution of dynamic permutations.
+        pub fn new(size: usize) -> Self {
+            Self::with_size(size)
+        }
+    }
+
+    impl EvenPerm<Dynamic> {
+        /// Creates the distribution of dynamic permutations.
+        pub fn new(size: usize) -> Self {
+            Self::with_size(size)
+        }
+    }
+
+    impl<C> CycleTypePerm<C, Dynamic>
+    where
+        C: AsRef<[usize]>,
+    {
+        /// Creates the distribution of dynamic permutations from cycle lengths in any order,
+        /// where zero lengths are ignored.
+        pub fn new(cycle_type: C) -> Self {
+            Self::with_cycle_type(cycle_type)
+        }
+    }
+
+    impl Distribution<PermD> for Derangement<Dynamic> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermD {
+            let mut perm = PermD::identity(self.size);
+            derangement(&mut perm.indices, rng);
+            perm
+        }
+    }
+
+    impl Distribution<PermD> for Involution<Dynamic> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermD {
+            let mut perm = PermD::identity(self.size);
+            involution(
+                &mut perm.indices,
+                &mut vec![0; self.size],
+                &mut vec![0.0; self.size],
+                rng,
+            );
+            perm
+        }
+    }
+
+    impl Distribution<PermD> for CyclicPerm<Dynamic> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermD {
+            let mut perm = PermD::identity(self.size);
+            sattolo(&mut perm.indices, rng);
+            perm
+        }
+    }
+
+    impl Distribution<PermD> for EvenPerm<Dynamic> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermD {
+            let mut perm = PermD::identity(self.size);
+            even(&mut perm.indices, &mut vec![false; self.size], rng);
+            perm
+        }
+    }
+
+    impl<C> Distribution<PermD> for CycleTypePerm<C, Dynamic>
+    where
+        C: AsRef<[usize]>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PermD {
+            let mut perm = PermD::identity(self.size);
+            with_cycle_type(
+                &mut perm.indices,
+                &mut vec![0; self.size],
+                self.cycle_type.as_ref(),
+                rng,
+            );
+            perm
+        }
+    }
+
+    impl<P> Distribution<P> for PermGroup<P>
+    where
+        P: Permutation + Clone + PartialEq,
+        for<'a> &'a P: Mul<&'a P, Output = P>,
+    {
+        /// Samples a uniformly random group element.
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> P {
+            // each element is a unique product of transversal elements
+            let choices: Vec<_> = self
+                .basic_orbit_lens()
+                .map(|len| rng.gen_range(0..len))
+                .collect();
+            self.element_at(choices)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{from_indices::PermFromIndices, perm_type::PermS, size::Static};
+        use rand::rngs::StdRng;
+        use std::collections::HashMap;
+
+        #[test]
+        fn dynamic_uniform_perm() {
+            let mut rng = StdRng::seed_from_u64(0);
+
+            let perm = PermD::random(1024, &mut rng);
+            assert_eq!(perm.len(), 1024);
+            assert!(PermD::from_indices(perm.indices()).is_ok());
+
+            let mut perm = PermD::identity(3);
+            let mut counts = HashMap::new();
+            let distribution = UniformPerm::new(4);
+            assert_eq!(distribution.size(), 4);
+
+            for _ in 0..24000 {
+                distribution.sample_into(&mut perm, &mut rng);
+                *counts.entry(perm.clone()).or_insert(0) += 1;
+            }
+            assert_eq!(counts.len(), 24);
+            assert!(counts.values().all(|&count| (900..1100).contains(&count)));
+
+            let mut perm = PermS::<4>::identity();
+            let mut counts = HashMap::new();
+            for _ in 0..24000 {
+                perm.shuffle(&mut rng);
+                *counts.entry(perm.clone()).or_insert(0) += 1;
+            }
+            assert_eq!(counts.len(), 24);
+            assert!(counts.values().all(|&count| (900..1100).contains(&count)));
+        }
+
+        fn check_uniform<D, P, F>(distribution: D, support: usize, mut check: F)
+        where
+            D: Distribution<P>,
+            P: Eq + std::hash::Hash,
+            F: FnMut(&P) -> bool,
+        {
+            let mut rng = StdRng::seed_from_u64(1);
+            let mut counts = HashMap::new();
+            let trials = support * 2000;
+
+            distribution
+                .sample_iter(&mut rng)
+                .take(trials)
+                .for_each(|perm| {
+                    assert!(check(&perm));
+                    *counts.entry(perm).or_insert(0) += 1;
+                });
+
+            assert_eq!(counts.len(), support);
+            assert!(counts.values().all(|&count| (1800..2200).contains(&count)));
+        }
+
+        #[test]
+        fn group_random_element() {
+            let generators = [PermD::cycle(5), PermD::swap(5, 0, 1).unwrap()];
+            let symmetric = PermGroup::new(5, &generators).unwrap();
+            check_uniform(symmetric, 120, |_: &PermD| true);
+
+            let generators = [PermS::<6>::cycle(), PermS::reverse_cycle()];
+            let cyclic = PermGroup::new_static(&generators);
+            check_uniform(cyclic.clone(), 6, |perm: &PermS<6>| cyclic.contains(perm));
+        }
+
+        #[test]
+        fn constrained_distributions() {
+            let no_fixed_points =
+                |perm: &PermD| perm.indices().iter().enumerate().all(|(i, &j)| i != j);
+            check_uniform(Derangement::new(4).unwrap(), 9, no_fixed_points);
+            check_uniform(Derangement::new(5).unwrap(), 44, no_fixed_points);
+            check_uniform(Derangement::new_static().unwrap(), 1, |perm: &PermS<2>| {
+                perm == &PermS::<2>::swap2()
+            });
+            assert!(Derangement::new(1).is_err());
+            assert!(Derangement::<Static<1>>::new_static().is_err());
+
+            let is_involution = |perm: &PermD| perm * perm == PermD::identity(perm.len());
+            check_uniform(Involution::new(4), 10, is_involution);
+            check_uniform(Involution::new(5), 26, is_involution);
+            check_uniform(Involution::new_static(), 76, |perm: &PermS<6>| {
+                perm * perm == PermS::<6>::identity()
+            });
+
+            let is_cyclic = |perm: &PermD| perm.cycles().count() == 1;
+            check_uniform(CyclicPerm::new(5), 24, is_cyclic);
+            check_uniform(CyclicPerm::new_static(), 6, |perm: &PermS<4>| {
+                perm.cycles().count() == 1
+            });
+
+            check_uniform(EvenPerm::new(4), 12, |perm: &PermD| perm.is_even());
+            check_uniform(EvenPerm::new_static(), 60, |perm: &PermS<5>| perm.is_even());
+
+            check_uniform(CycleTypePerm::new([2, 1, 2]), 15, |perm: &PermD| {
+                perm.cycle_type() == vec![2, 2, 1]
+            });
+            check_uniform(
+                CycleTypePerm::new_static(vec![3, 2]).unwrap(),
+                20,
+                |perm: &PermS<5>| perm.cycle_type() == [3, 2, 0, 0, 0],
+            );
+            check_uniform(CycleTypePerm::new([2, 0, 1]), 3, |perm: &PermD| {
+                perm.cycle_type() == vec![2, 1]
+            });
+
+            // cycle types are zero-padded, and zero lengths are ignored
+            let mut rng = StdRng::seed_from_u64(1);
+            let perm: PermS<5> = CycleTypePerm::new_static([2, 2, 1, 0, 0])
+                .unwrap()
+                .sample(&mut rng);
+            let distribution =
+                CycleTypePerm::<_, Static<5>>::new_static(perm.cycle_type()).unwrap();
+            assert_eq!(distribution.sample(&mut rng).cycle_type(), [2, 2, 1, 0, 0]);
+            let perm = PermD::from_indices([1, 0, 3, 4, 2, 5]).unwrap();
+            let distribution = CycleTypePerm::new(perm.cycle_type());
+            assert_eq!(distribution.size(), 6);
+            assert_eq!(
+                distribution.sample(&mut rng).cycle_type(),
+                perm.cycle_type()
+            );
+            assert_eq!(
+                CycleTypePerm::<_, Static<4>>::new_static([3, 2]).unwrap_err(),
+                PermError::LengthMismatch {
+                    expected: 4,
+                    actual: 5
+                }
+            );
+
+            let mut rng = StdRng::seed_from_u64(2);
+            let perm: PermD = Derangement::new(1000).unwrap().sample(&mut rng);
+            assert!(no_fixed_points(&perm));
+            let perm: PermD = Involution::new(1000).sample(&mut rng);
+            assert!(is_involution(&perm));
+        }
+    }
+}
+
+/// Shuffles identity indices until no fixed point is left.
+fn derangement<R: Rng + ?Sized>(indices: &mut [usize], rng: &mut R) {
+    // the expected number of trials approaches e
+    loop {
+        indices.shuffle(rng);
+        if indices.iter().enumerate().all(|(src, &dst)| src != dst) {
+            break;
+        }
+    }
+}
+
+/// Builds a random involution on identity indices.
+fn involution<R: Rng + ?Sized>(
+    indices: &mut [usize],
+    pool: &mut [usize],
+    ratios: &mut [f64],
+    rng: &mut R,
+) {
+    let len = indices.len();
+
+    // ratios[m - 1] = I(m - 1) / I(m), where I(m) is the number of involutions of size m
+    // that satisfies I(m) = I(m - 1) + (m - 1) I(m - 2)
+    (0..len).for_each(|index| {
+        ratios[index] = if index == 0 {
+            1.0
+        } else {
+            1.0 / (1.0 + index as f64 * ratios[index - 1])
+        };
+    });
+    pool.iter_mut()
+        .enumerate()
+        .for_each(|(index, elem)| *elem = index);
+
+    // pool[..remaining] holds unpaired elements
+    let mut remaining = len;
+    while remaining > 0 {
+        let elem = pool[remaining - 1];
+
+        if rng.gen_bool(ratios[remaining - 1]) {
+            indices[elem] = elem;
+            remaining -= 1;
+        } else {
+            let nth = rng.gen_range(0..(remaining - 1));
+            let other = pool[nth];
+            indices[elem] = other;
+            indices[other] = elem;
+            pool.swap(nth, remaining - 2);
+            remaining -= 2;
+        }
+    }
+}
+
+/// Builds a random single cycle on identity indices by Sattolo's algorithm.
+fn sattolo<R: Rng + ?Sized>(indices: &mut [usize], rng: &mut R) {
+    (1..indices.len()).rev().for_each(|index| {
+        let other = rng.gen_range(0..index);
+        indices.swap(index, other);
+    });
+}
+
+/// Builds a random even permutation on identity indices.
+fn even<R: Rng + ?Sized>(indices: &mut [usize], visited: &mut [bool], rng: &mut R) {
+    let len = indices.len();
+    indices.shuffle(rng);
+
+    // swapping two entries is a bijection between odd and even permutations
+    let num_cycles = Cycles::new(indices, visited).count();
+    if (len - num_cycles) % 2 == 1 {
+        indices.swap(0, 1);
+    }
+}
+
+/// Builds a random permutation with given cycle lengths on identity indices.
+fn with_cycle_type<R: Rng + ?Sized>(
+    indices: &mut [usize],
+    pool: &mut [usize],
+    cycle_type: &[usize],
+    rng: &mut R,
+) {
+    pool.iter_mut()
+        .enumerate()
+        .for_each(|(index, elem)| *elem = index);
+    pool.shuffle(rng);
+
+    let mut offset = 0;
+    cycle_type.iter().for_each(|&len| {
+        let cycle = &pool[offset..(offset + len)];
+        cycle
+            .iter()
+            .zip(cycle.iter().skip(1).chain(cycle.first()))
+            .for_each(|(&src, &dst)| {
+                indices[src] = dst;
+            });
+        offset += len;
+    });
 }
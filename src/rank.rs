@@ -0,0 +1,304 @@
+use crate::{error::PermError, fenwick::Fenwick, perm_type::Perm, size::PermSize};
+
+impl<S> Perm<S>
+where
+    S: PermSize,
+    S::Container: Clone,
+{
+    /// Gets the rank of permutation among all permutations of the same size in lexicographic order.
+    ///
+    /// The identity has rank 0. It always succeeds for sizes up to 34, and fails
+    /// if the rank overflows `u128`.
+    pub fn rank(&self) -> Result<u128, PermError> {
+        lexicographic_rank(
+            self.indices.as_ref(),
+            self.scratch().as_mut(),
+            0u128,
+            |rank, base, digit| rank.checked_mul(base as u128)?.checked_add(digit as u128),
+        )
+    }
+
+    /// Gets the lexicographic rank as `u64`.
+    ///
+    /// It always succeeds for sizes up to 20, and fails if the rank overflows `u64`.
+    pub fn rank_u64(&self) -> Result<u64, PermError> {
+        lexicographic_rank(
+            self.indices.as_ref(),
+            self.scratch().as_mut(),
+            0u64,
+            |rank, base, digit| rank.checked_mul(base as u64)?.checked_add(digit as u64),
+        )
+    }
+}
+
+mod without_std {
+    use super::*;
+    use crate::perm_type::PermS;
+
+    impl<const SIZE: usize> PermS<SIZE> {
+        /// Builds the permutation with given lexicographic rank.
+        ///
+        /// It fails if the rank is not less than `SIZE!`.
+        pub fn from_rank(rank: u128) -> Result<Self, PermError> {
+            let mut perm = Self::identity();
+            lexicographic_unrank(&mut perm.indices, &mut [0; SIZE], rank, |rank, base| {
+                let digit = *rank % base as u128;
+                *rank /= base as u128;
+                digit as usize
+            })?;
+            Ok(perm)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::from_indices::PermFromIndices;
+
+        fn check_all_ranks<const SIZE: usize>(count: u128) {
+            let mut prev: Option<PermS<SIZE>> = None;
+
+            (0..count).for_each(|rank| {
+                let perm = PermS::<SIZE>::from_rank(rank).unwrap();
+                assert_eq!(perm.rank().unwrap(), rank);
+                assert_eq!(perm.rank_u64().unwrap(), rank as u64);
+                if let Some(prev) = &prev {
+                    assert!(prev.indices < perm.indices);
+                }
+                prev = Some(perm);
+            });
+
+            assert!(PermS::<SIZE>::from_rank(count).is_err());
+        }
+
+        #[test]
+        fn static_rank() {
+            check_all_ranks::<0>(1);
+            check_all_ranks::<1>(1);
+            check_all_ranks::<2>(2);
+            check_all_ranks::<3>(6);
+            check_all_ranks::<4>(24);
+            check_all_ranks::<5>(120);
+            check_all_ranks::<6>(720);
+
+            assert_eq!(PermS::<4>::identity().rank().unwrap(), 0);
+            assert_eq!(
+                PermS::from_indices([3, 2, 1, 0]).unwrap().rank().unwrap(),
+                23
+            );
+            assert_eq!(
+                PermS::from_indices([1, 0, 3, 2]).unwrap().rank().unwrap(),
+                7
+            );
+
+            // 34! - 1 fits u128, while ranks of larger sizes fail only on overflow
+            let mut reversed = [0; 34];
+            reversed
+                .iter_mut()
+                .enumerate()
+                .for_each(|(index, elem)| *elem = 33 - index);
+            let perm = PermS::from_indices(reversed).unwrap();
+            let rank = perm.rank().unwrap();
+            assert_eq!(rank, (1..=34u128).product::<u128>() - 1);
+            assert!(perm.rank_u64().is_err());
+            assert_eq!(PermS::<34>::from_rank(rank).unwrap(), perm);
+
+            let perm = PermS::<21>::from_rank(u64::MAX as u128).unwrap();
+            assert_eq!(perm.rank_u64().unwrap(), u64::MAX);
+            let perm = PermS::<21>::from_rank(u64::MAX as u128 + 1).unwrap();
+            assert_eq!(perm.rank_u64(), Err(PermError::RankOverflow { len: 21 }));
+            assert!(PermS::<20>::from_rank(u64::MAX as u128).is_err());
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use crate::{common::*, perm_type::PermD};
+
+    impl PermD {
+        /// Builds the permutation of given size with given lexicographic rank.
+        ///
+        /// It fails if the rank is not less than `size!`.
+        pub fn from_rank(size: usize, rank: u128) -> Result<Self, PermError> {
+            let mut perm = Self::identity(size);
+            lexicographic_unrank(&mut perm.indices, &mut vec![0; size], rank, |rank, base| {
+                let digit = *rank % base as u128;
+                *rank /= base as u128;
+                digit as usize
+            })?;
+            Ok(perm)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::perm_trait::Permutation;
+
+        #[test]
+        fn dynamic_rank() {
+            (0..=5).for_each(|size| {
+                let count: u128 = (1..=size as u128).product();
+                (0..count).for_each(|rank| {
+                    let perm = PermD::from_rank(size, rank).unwrap();
+                    assert_eq!(perm.rank().unwrap(), rank);
+                });
+                assert_eq!(
+                    PermD::from_rank(size, count),
+                    Err(PermError::RankOutOfRange { len: size })
+                );
+            });
+
+            // small ranks of large permutations only move the tail
+            let perm = PermD::from_rank(100, 5).unwrap();
+            assert_eq!(&perm.indices()[97..], &[99, 98, 97]);
+            assert_eq!(perm.rank().unwrap(), 5);
+            assert_eq!(PermD::from_rank(1000, 0).unwrap(), PermD::identity(1000));
+
+            // ranking takes O(n log n) time, so large sizes are fast
+            let perm = PermD::from_rank(100_000, 5).unwrap();
+            assert_eq!(perm.rank().unwrap(), 5);
+            assert_eq!(PermD::identity(100_000).rank_u64().unwrap(), 0);
+
+            let mut reversed = PermD::identity(35);
+            reversed.indices.reverse();
+            assert_eq!(reversed.rank(), Err(PermError::RankOverflow { len: 35 }));
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+mod with_bigint {
+    use super::*;
+    use crate::{common::*, perm_type::PermD};
+    use num_bigint::BigUint;
+
+    impl<S> Perm<S>
+    where
+        S: PermSize,
+        S::Container: Clone,
+    {
+        /// Gets the lexicographic rank as a big integer, which never overflows.
+        pub fn rank_big(&self) -> BigUint {
+            lexicographic_rank(
+                self.indices.as_ref(),
+                self.scratch().as_mut(),
+                BigUint::default(),
+                |rank, base, digit| Some(rank * base + digit),
+            )
+            .unwrap()
+        }
+    }
+
+    impl PermD {
+        /// Builds the permutation of given size with lexicographic rank in a big integer.
+        ///
+        /// It fails if the rank is not less than `size!`.
+        pub fn from_rank_big(size: usize, rank: &BigUint) -> Result<Self, PermError> {
+            let mut perm = Self::identity(size);
+            lexicographic_unrank(
+                &mut perm.indices,
+                &mut vec![0; size],
+                rank.clone(),
+                |rank, base| {
+                    let digit = &*rank % base;
+                    *rank /= base;
+                    usize::try_from(&digit).unwrap()
+                },
+            )?;
+            Ok(perm)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn big_rank() {
+            (0..=5).for_each(|size| {
+                let count: u128 = (1..=size as u128).product();
+                (0..count).for_each(|rank| {
+                    let perm = PermD::from_rank(size, rank).unwrap();
+                    assert_eq!(perm.rank_big(), BigUint::from(rank));
+                    assert_eq!(
+                        PermD::from_rank_big(size, &BigUint::from(rank)).unwrap(),
+                        perm
+                    );
+                });
+            });
+
+            let size = 100;
+            let count: BigUint = (1..=size).map(BigUint::from).product();
+            let mut reversed = PermD::identity(size);
+            reversed.indices.reverse();
+            assert_eq!(reversed.rank_big(), &count - 1u32);
+            assert_eq!(
+                PermD::from_rank_big(size, &(&count - 1u32)).unwrap(),
+                reversed
+            );
+            assert_eq!(
+                PermD::from_rank_big(size, &count),
+                Err(PermError::RankOutOfRange { len: size })
+            );
+        }
+    }
+}
+
+/// Computes the lexicographic rank by Horner's rule over the Lehmer code.
+fn lexicographic_rank<T, F>(
+    indices: &[usize],
+    tree: &mut [usize],
+    zero: T,
+    mut step: F,
+) -> Result<T, PermError>
+where
+    F: FnMut(T, usize, usize) -> Option<T>,
+{
+    let len = indices.len();
+    let mut remaining = Fenwick::full(tree);
+
+    // the digit counts the smaller indices to the right, in base (len - position)
+    indices
+        .iter()
+        .enumerate()
+        .try_fold(zero, |rank, (position, &index)| {
+            let digit = remaining.count_less(index);
+            remaining.remove(index);
+            step(rank, len - position, digit)
+        })
+        .ok_or(PermError::RankOverflow { len })
+}
+
+/// Writes the permutation of given lexicographic rank on indices.
+fn lexicographic_unrank<T, F>(
+    indices: &mut [usize],
+    tree: &mut [usize],
+    mut rank: T,
+    mut div_rem: F,
+) -> Result<(), PermError>
+where
+    T: PartialEq + Default,
+    F: FnMut(&mut T, usize) -> usize,
+{
+    let len = indices.len();
+
+    // extract the Lehmer code from the last digit, whose base is 1
+    (0..len).rev().for_each(|position| {
+        indices[position] = div_rem(&mut rank, len - position);
+    });
+    if rank != T::default() {
+        return Err(PermError::RankOutOfRange { len });
+    }
+
+    // pick the digit-th unused index at each position
+    let mut unused = Fenwick::full(tree);
+    indices.iter_mut().for_each(|slot| {
+        let index = unused.select(*slot);
+        unused.remove(index);
+        *slot = index;
+    });
+    Ok(())
+}
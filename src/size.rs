@@ -1,13 +1,13 @@
 //! Permutation size markers.
 
-#[cfg(feature = "std")]
-pub use with_std::*;
+#[cfg(feature = "alloc")]
+pub use with_alloc::*;
 pub use without_std::*;
 
 /// The permutation size marker trait.
 pub trait PermSize
 where
-    Self::Container: AsRef<[usize]>,
+    Self::Container: AsRef<[usize]> + AsMut<[usize]>,
 {
     type Container;
 }
@@ -24,9 +24,10 @@ mod without_std {
     pub struct Static<const SIZE: usize>;
 }
 
-#[cfg(feature = "std")]
-mod with_std {
+#[cfg(feature = "alloc")]
+mod with_alloc {
     use super::*;
+    use crate::common::*;
 
     /// The dynamic size marker type.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]